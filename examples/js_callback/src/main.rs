@@ -1,5 +1,4 @@
-use wasm_bindgen::prelude::*;
-use yew::{prelude::*, web_sys::HtmlTextAreaElement};
+use yew::{callback::JsCallback, prelude::*, web_sys::HtmlTextAreaElement};
 
 mod bindings;
 
@@ -12,6 +11,8 @@ pub struct Model {
     payload: String,
     // Pointless field just to have something that's been manipulated
     debugged_payload: String,
+    // Keeps the function passed to JS alive until it's called
+    payload_callback: Option<JsCallback<String>>,
 }
 
 impl Component for Model {
@@ -22,6 +23,7 @@ impl Component for Model {
         Self {
             payload: String::default(),
             debugged_payload: format!("{:?}", ""),
+            payload_callback: None,
         }
     }
 
@@ -37,10 +39,9 @@ impl Component for Model {
                 }
             }
             Msg::AsyncPayload => {
-                let callback = ctx.link().callback(Msg::Payload);
-                bindings::get_payload_later(Closure::once_into_js(move |payload: String| {
-                    callback.emit(payload)
-                }));
+                let callback = ctx.link().js_callback_serde(Msg::Payload);
+                bindings::get_payload_later(callback.as_function().clone().into());
+                self.payload_callback = Some(callback);
                 false
             }
         }
@@ -25,12 +25,22 @@ impl Component for ListHeader {
             e.stop_propagation();
             Hovered::Header
         });
+        let onkeydown = list_link.batch_callback(|e: KeyboardEvent| {
+            if e.key() == "Enter" {
+                Some(ListMsg::HeaderClick)
+            } else {
+                None
+            }
+        });
 
         html! {
             <div
                 class="list-header"
+                role="button"
+                tabindex="0"
                 {onmouseover}
                 onclick={list_link.callback(|_| ListMsg::HeaderClick)}
+                {onkeydown}
             >
                 { &ctx.props().text }
             </div>
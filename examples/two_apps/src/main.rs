@@ -1,5 +1,5 @@
 use yew::html::Scope;
-use yew::{html, AppHandle, Component, Context, Html};
+use yew::{html, App, AppHandle, Component, Context, Html};
 
 pub enum Msg {
     SetOpposite(Scope<Model>),
@@ -72,7 +72,11 @@ impl Component for Model {
 fn mount_app(selector: &'static str) -> AppHandle<Model> {
     let document = yew::utils::document();
     let element = document.query_selector(selector).unwrap().unwrap();
-    yew::start_app_in_element(element)
+    // Each app handles the events of its own subtree
+    App::<Model>::builder()
+        .mount_point(element.clone())
+        .delegation_root(element)
+        .mount()
 }
 
 fn main() {
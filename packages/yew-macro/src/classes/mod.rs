@@ -1,30 +1,65 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{Delimiter, TokenStream};
 use quote::{quote, quote_spanned, ToTokens};
+use std::collections::HashSet;
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
-use syn::{Expr, ExprLit, Lit, LitStr, Token};
+use syn::{braced, Expr, ExprLit, Lit, LitStr, Token};
 
 /// List of HTML classes.
-pub struct Classes(Punctuated<ClassExpr, Token![,]>);
+pub struct Classes(Punctuated<ClassItem, Token![,]>);
 
 impl Parse for Classes {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        input.parse_terminated(ClassExpr::parse).map(Self)
+        input.parse_terminated(ClassItem::parse).map(Self)
     }
 }
 
 impl ToTokens for Classes {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let n = self.0.len();
-        let push_classes = self.0.iter().map(|x| match x {
-            ClassExpr::Lit(class) => quote! {
-                unsafe { __yew_classes.unchecked_push(#class) };
-            },
-            ClassExpr::Expr(class) => quote_spanned! {class.span()=>
-                __yew_classes.push(#class);
-            },
-        });
+        let classes: Vec<_> = self
+            .0
+            .iter()
+            .flat_map(|item| match item {
+                ClassItem::Single(class) => vec![class],
+                ClassItem::Map(classes) => classes.iter().collect(),
+            })
+            .collect();
+
+        // Literal classes which are always added only need to be pushed once
+        let mut seen = HashSet::new();
+        let classes: Vec<_> = classes
+            .into_iter()
+            .filter(|class| match class {
+                ConditionalClass {
+                    class: ClassExpr::Lit(lit),
+                    condition: None,
+                } => seen.insert(lit.value()),
+                _ => true,
+            })
+            .collect();
+
+        let n = classes.len();
+        let push_classes = classes
+            .into_iter()
+            .map(|ConditionalClass { class, condition }| {
+                let push = match class {
+                    ClassExpr::Lit(class) => quote! {
+                        unsafe { __yew_classes.unchecked_push(#class) };
+                    },
+                    ClassExpr::Expr(class) => quote_spanned! {class.span()=>
+                        __yew_classes.push(#class);
+                    },
+                };
+                match condition {
+                    Some(condition) => quote_spanned! {condition.span()=>
+                        if #condition {
+                            #push
+                        }
+                    },
+                    None => push,
+                }
+            });
         tokens.extend(quote! {
             {
                 let mut __yew_classes = ::yew::html::Classes::with_capacity(#n);
@@ -35,6 +70,63 @@ impl ToTokens for Classes {
     }
 }
 
+/// An item of the list, which is either a class or a map of classes to conditions.
+enum ClassItem {
+    Single(ConditionalClass),
+    Map(Vec<ConditionalClass>),
+}
+
+impl Parse for ClassItem {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if is_class_map(input) {
+            let content;
+            braced!(content in input);
+            let entries = content.parse_terminated::<_, Token![,]>(|input| {
+                let class = input.parse::<LitStr>()?;
+                input.parse::<Token![:]>()?;
+                let condition = input.parse()?;
+                Ok(ConditionalClass {
+                    class: ClassExpr::Lit(validate_literal(class)?),
+                    condition: Some(condition),
+                })
+            })?;
+            return Ok(Self::Map(entries.into_iter().collect()));
+        }
+
+        let class = input.parse()?;
+        let condition = if input.peek(Token![=>]) {
+            input.parse::<Token![=>]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        Ok(Self::Single(ConditionalClass { class, condition }))
+    }
+}
+
+/// Returns true if the input starts with `{ "class": ...`.
+fn is_class_map(input: ParseStream) -> bool {
+    let inside = match input.cursor().group(Delimiter::Brace) {
+        Some((inside, _, _)) => inside,
+        None => return false,
+    };
+    let (lit, rest) = match inside.literal() {
+        Some(literal) => literal,
+        None => return false,
+    };
+    syn::parse_str::<LitStr>(&lit.to_string()).is_ok()
+        && match rest.punct() {
+            Some((punct, _)) => punct.as_char() == ':',
+            None => false,
+        }
+}
+
+/// A class which is only added if the condition is true.
+struct ConditionalClass {
+    class: ClassExpr,
+    condition: Option<Box<Expr>>,
+}
+
 enum ClassExpr {
     Lit(LitStr),
     Expr(Box<Expr>),
@@ -46,26 +138,28 @@ impl Parse for ClassExpr {
             Expr::Lit(ExprLit {
                 lit: Lit::Str(lit_str),
                 ..
-            }) => {
-                let value = lit_str.value();
-                let classes = value.split_whitespace().collect::<Vec<_>>();
-                if classes.len() > 1 {
-                    let fix = classes
-                        .into_iter()
-                        .map(|class| format!("\"{}\"", class))
-                        .collect::<Vec<_>>()
-                        .join(", ");
-                    let msg = format!(
-                        "string literals must not contain more than one class (hint: use `{}`)",
-                        fix
-                    );
-
-                    Err(syn::Error::new(lit_str.span(), msg))
-                } else {
-                    Ok(Self::Lit(lit_str))
-                }
-            }
+            }) => validate_literal(lit_str).map(Self::Lit),
             expr => Ok(Self::Expr(Box::new(expr))),
         }
     }
 }
+
+fn validate_literal(lit_str: LitStr) -> syn::Result<LitStr> {
+    let value = lit_str.value();
+    let classes = value.split_whitespace().collect::<Vec<_>>();
+    if classes.len() > 1 {
+        let fix = classes
+            .into_iter()
+            .map(|class| format!("\"{}\"", class))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let msg = format!(
+            "string literals must not contain more than one class (hint: use `{}`)",
+            fix
+        );
+
+        Err(syn::Error::new(lit_str.span(), msg))
+    } else {
+        Ok(lit_str)
+    }
+}
@@ -0,0 +1,177 @@
+//! The `css!` macro compiles a stylesheet which is scoped to a generated class name.
+//!
+//! Declarations at the top level apply to the class itself. Nested rules are relative to the rule
+//! they're in: `&` is replaced by the parent selector, selectors starting with `:` are appended to
+//! the parent selector and any other selector matches descendants of the parent. `@media` and
+//! `@supports` rules can be nested too, other at-rules are copied as they are.
+
+mod parser;
+
+use parser::{Item, Parser};
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::parse::{Parse, ParseStream};
+use syn::LitStr;
+
+pub struct Css {
+    class: String,
+    css: String,
+}
+
+impl Parse for Css {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let lit = input.parse::<LitStr>().map_err(|err| {
+            syn::Error::new(err.span(), "expected a string literal containing css")
+        })?;
+        if !input.is_empty() {
+            return Err(input.error("unexpected input after the css"));
+        }
+
+        let source = lit.value();
+        let items = Parser::new(&source)
+            .parse()
+            .map_err(|err| syn::Error::new(lit.span(), err.to_string()))?;
+
+        let class = format!("yew-{:08x}", fnv1a(source.as_bytes()));
+        let mut css = String::new();
+        compile(&items, &[format!(".{}", class)], &mut css);
+        Ok(Self { class, css })
+    }
+}
+
+impl ToTokens for Css {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { class, css } = self;
+        tokens.extend(quote! {
+            ::yew::style::Style::__new(#class, #css)
+        });
+    }
+}
+
+/// Writes the rules for `items` to `out`, where `parents` are the selectors of the enclosing rule.
+fn compile(items: &[Item], parents: &[String], out: &mut String) {
+    let declarations: Vec<_> = items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Declaration(declaration) => Some(declaration.as_str()),
+            _ => None,
+        })
+        .collect();
+    if !declarations.is_empty() {
+        out.push_str(&parents.join(","));
+        out.push('{');
+        out.push_str(&declarations.join(";"));
+        out.push('}');
+    }
+
+    for item in items {
+        match item {
+            Item::Declaration(_) => {}
+            Item::Rule { selector, items } => {
+                compile(items, &resolve_selectors(selector, parents), out);
+            }
+            Item::ConditionalRule { prelude, items } => {
+                out.push_str(prelude);
+                out.push('{');
+                compile(items, parents, out);
+                out.push('}');
+            }
+            Item::Verbatim(rule) => out.push_str(rule),
+        }
+    }
+}
+
+/// Combines every selector in the comma separated list with every parent selector.
+fn resolve_selectors(selector: &str, parents: &[String]) -> Vec<String> {
+    let mut selectors = Vec::new();
+    for selector in parser::split_selectors(selector) {
+        for parent in parents {
+            selectors.push(if selector.contains('&') {
+                selector.replace('&', parent)
+            } else if selector.starts_with(':') {
+                format!("{}{}", parent, selector)
+            } else {
+                format!("{} {}", parent, selector)
+            });
+        }
+    }
+    selectors
+}
+
+/// The 32 bit FNV-1a hash, which is stable across compiler versions unlike `DefaultHasher`.
+fn fnv1a(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(0x811c_9dc5, |hash, byte| {
+        (hash ^ u32::from(*byte)).wrapping_mul(0x0100_0193)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compile_str(source: &str) -> String {
+        let items = Parser::new(source).parse().unwrap();
+        let mut css = String::new();
+        compile(&items, &[".c".to_owned()], &mut css);
+        css
+    }
+
+    #[test]
+    fn declarations_apply_to_the_class() {
+        assert_eq!(
+            compile_str("color: red;\n  margin : 0 auto "),
+            ".c{color:red;margin:0 auto}"
+        );
+    }
+
+    #[test]
+    fn nested_rules_are_relative_to_the_parent() {
+        assert_eq!(
+            compile_str("color: red; &:hover { color: blue; } span, > p { margin: 0; }"),
+            ".c{color:red}.c:hover{color:blue}.c span,.c > p{margin:0}"
+        );
+        assert_eq!(
+            compile_str(":focus-visible, .dark & { outline: none; }"),
+            ".c:focus-visible,.dark .c{outline:none}"
+        );
+    }
+
+    #[test]
+    fn media_queries_contain_the_scoped_rules() {
+        assert_eq!(
+            compile_str("@media (max-width: 600px) { width: 100%; a { color: red } }"),
+            "@media (max-width: 600px){.c{width:100%}.c a{color:red}}"
+        );
+    }
+
+    #[test]
+    fn other_at_rules_are_copied() {
+        assert_eq!(
+            compile_str("@keyframes spin { to { transform: rotate(1turn); } } animation: spin 1s;"),
+            ".c{animation:spin 1s}@keyframes spin { to { transform: rotate(1turn); } }"
+        );
+    }
+
+    #[test]
+    fn strings_and_comments() {
+        assert_eq!(
+            compile_str("/* icon */ &::before { content: \"a  ;{b\"; }"),
+            ".c::before{content:\"a  ;{b\"}"
+        );
+    }
+
+    #[test]
+    fn errors_mention_the_line() {
+        let err = |source| Parser::new(source).parse().unwrap_err().to_string();
+        assert_eq!(
+            err("color: red;\ncolor blue;"),
+            "line 2: expected `:` in the declaration `color blue`"
+        );
+        assert_eq!(err("a {\n color: red;"), "line 1: unclosed block");
+        assert_eq!(err("color: red; }"), "line 1: unexpected `}`");
+        assert_eq!(
+            err(" { color: red; }"),
+            "line 1: expected a selector before `{`"
+        );
+    }
+}
@@ -0,0 +1,302 @@
+use std::fmt;
+
+/// An item in a block of css.
+#[derive(Debug)]
+pub enum Item {
+    /// A `property:value` pair with normalized whitespace.
+    Declaration(String),
+    /// A nested rule, the selector is relative to the parent rule.
+    Rule { selector: String, items: Vec<Item> },
+    /// An `@media` or `@supports` rule, which contains the rules of the parent.
+    ConditionalRule { prelude: String, items: Vec<Item> },
+    /// Any other at-rule, which is copied without scoping it.
+    Verbatim(String),
+}
+
+#[derive(Debug)]
+pub struct Error {
+    line: usize,
+    message: String,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+pub struct Parser<'a> {
+    source: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Self { source, pos: 0 }
+    }
+
+    pub fn parse(mut self) -> Result<Vec<Item>, Error> {
+        let items = self.parse_items()?;
+        if self.peek().is_some() {
+            return Err(self.error(self.pos, "unexpected `}`"));
+        }
+        Ok(items)
+    }
+
+    /// Parses items until the end of the input or a closing brace.
+    fn parse_items(&mut self) -> Result<Vec<Item>, Error> {
+        let mut items = Vec::new();
+        loop {
+            self.skip_whitespace_and_comments();
+            let start = self.pos;
+            match self.peek() {
+                None | Some('}') => return Ok(items),
+                Some(';') => self.pos += 1,
+                Some('@') => items.push(self.parse_at_rule()?),
+                Some(_) => {
+                    let text = self.read_until_terminator();
+                    if self.peek() == Some('{') {
+                        let selector = normalize(text);
+                        if selector.is_empty() {
+                            return Err(self.error(start, "expected a selector before `{`"));
+                        }
+                        let items_start = self.pos;
+                        self.pos += 1;
+                        let nested = self.parse_block(items_start)?;
+                        items.push(Item::Rule {
+                            selector,
+                            items: nested,
+                        });
+                    } else {
+                        if self.peek() == Some(';') {
+                            self.pos += 1;
+                        }
+                        items.push(self.declaration(text, start)?);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Parses the items of a block whose opening brace is at `open`, including the closing brace.
+    fn parse_block(&mut self, open: usize) -> Result<Vec<Item>, Error> {
+        let items = self.parse_items()?;
+        if self.peek() != Some('}') {
+            return Err(self.error(open, "unclosed block"));
+        }
+        self.pos += 1;
+        Ok(items)
+    }
+
+    fn parse_at_rule(&mut self) -> Result<Item, Error> {
+        let start = self.pos;
+        let prelude = normalize(self.read_until_terminator());
+        match self.peek() {
+            Some('{') if prelude.starts_with("@media") || prelude.starts_with("@supports") => {
+                let open = self.pos;
+                self.pos += 1;
+                let items = self.parse_block(open)?;
+                Ok(Item::ConditionalRule { prelude, items })
+            }
+            Some('{') => {
+                self.skip_block()?;
+                Ok(Item::Verbatim(self.source[start..self.pos].to_owned()))
+            }
+            Some(';') => {
+                self.pos += 1;
+                Ok(Item::Verbatim(format!("{};", prelude)))
+            }
+            _ => Err(self.error(
+                start,
+                format!("expected `{{` or `;` after the at-rule `{}`", prelude),
+            )),
+        }
+    }
+
+    fn declaration(&self, text: &str, start: usize) -> Result<Item, Error> {
+        let text = normalize(text);
+        match text.find(':') {
+            Some(colon) => Ok(Item::Declaration(format!(
+                "{}:{}",
+                text[..colon].trim_end(),
+                text[colon + 1..].trim_start()
+            ))),
+            None => Err(self.error(start, format!("expected `:` in the declaration `{}`", text))),
+        }
+    }
+
+    /// Skips a block, whose opening brace is at the current position, without parsing it.
+    fn skip_block(&mut self) -> Result<(), Error> {
+        let open = self.pos;
+        let mut depth = 0;
+        while let Some(c) = self.peek() {
+            match c {
+                '{' => depth += 1,
+                '}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        self.pos += 1;
+                        return Ok(());
+                    }
+                }
+                '"' | '\'' => {
+                    self.skip_string();
+                    continue;
+                }
+                '/' if self.source[self.pos..].starts_with("/*") => {
+                    self.skip_comment();
+                    continue;
+                }
+                _ => {}
+            }
+            self.pos += c.len_utf8();
+        }
+        Err(self.error(open, "unclosed block"))
+    }
+
+    /// Reads up to the next `;`, `{` or `}` which isn't in a string, comment or parentheses.
+    fn read_until_terminator(&mut self) -> &'a str {
+        let start = self.pos;
+        let mut parens = 0;
+        while let Some(c) = self.peek() {
+            match c {
+                ';' | '{' | '}' if parens == 0 => break,
+                '(' => parens += 1,
+                ')' => parens -= 1,
+                '"' | '\'' => {
+                    self.skip_string();
+                    continue;
+                }
+                '/' if self.source[self.pos..].starts_with("/*") => {
+                    self.skip_comment();
+                    continue;
+                }
+                _ => {}
+            }
+            self.pos += c.len_utf8();
+        }
+        &self.source[start..self.pos]
+    }
+
+    fn skip_whitespace_and_comments(&mut self) {
+        loop {
+            let rest = &self.source[self.pos..];
+            let trimmed = rest.trim_start();
+            self.pos += rest.len() - trimmed.len();
+            if trimmed.starts_with("/*") {
+                self.skip_comment();
+            } else {
+                return;
+            }
+        }
+    }
+
+    fn skip_comment(&mut self) {
+        self.pos = match self.source[self.pos + 2..].find("*/") {
+            Some(end) => self.pos + 2 + end + 2,
+            None => self.source.len(),
+        };
+    }
+
+    fn skip_string(&mut self) {
+        self.pos += string_len(&self.source[self.pos..]);
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source[self.pos..].chars().next()
+    }
+
+    fn error(&self, pos: usize, message: impl Into<String>) -> Error {
+        Error {
+            line: self.source[..pos].matches('\n').count() + 1,
+            message: message.into(),
+        }
+    }
+}
+
+/// Returns the length of the quoted string at the start of `s`, including the quotes.
+fn string_len(s: &str) -> usize {
+    let mut chars = s.char_indices();
+    let quote = match chars.next() {
+        Some((_, quote)) => quote,
+        None => return 0,
+    };
+    let mut escaped = false;
+    for (i, c) in chars {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if c == quote {
+            return i + c.len_utf8();
+        }
+    }
+    s.len()
+}
+
+/// Removes comments and collapses whitespace outside of strings.
+fn normalize(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut space = false;
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        if c.is_whitespace() {
+            space = true;
+            rest = &rest[c.len_utf8()..];
+            continue;
+        }
+        if rest.starts_with("/*") {
+            space = true;
+            rest = match rest[2..].find("*/") {
+                Some(end) => &rest[2 + end + 2..],
+                None => "",
+            };
+            continue;
+        }
+
+        if space && !out.is_empty() {
+            out.push(' ');
+        }
+        space = false;
+        let len = if c == '"' || c == '\'' {
+            string_len(rest)
+        } else {
+            c.len_utf8()
+        };
+        out.push_str(&rest[..len]);
+        rest = &rest[len..];
+    }
+    out
+}
+
+/// Splits a selector list at the commas which aren't in parentheses or strings.
+pub fn split_selectors(selector: &str) -> Vec<&str> {
+    let mut selectors = Vec::new();
+    let mut parens = 0;
+    let mut start = 0;
+    let mut rest = selector;
+    let mut pos = 0;
+    while let Some(c) = rest.chars().next() {
+        let len = match c {
+            '"' | '\'' => string_len(rest),
+            '(' => {
+                parens += 1;
+                1
+            }
+            ')' => {
+                parens -= 1;
+                1
+            }
+            ',' if parens == 0 => {
+                selectors.push(selector[start..pos].trim());
+                start = pos + 1;
+                1
+            }
+            c => c.len_utf8(),
+        };
+        pos += len;
+        rest = &rest[len..];
+    }
+    selectors.push(selector[start..].trim());
+    selectors
+}
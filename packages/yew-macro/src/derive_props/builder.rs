@@ -49,6 +49,24 @@ impl ToTokens for PropsBuilder<'_> {
         let step_generics =
             with_param_bounds(generics, step_generic_param.clone(), (*step_trait).clone());
 
+        let rest = match props.prop_fields.iter().find(|pf| pf.is_rest()) {
+            Some(rest) => {
+                let name = rest.name();
+                quote! { ::std::option::Option::Some(&mut self.wrapped.#name) }
+            }
+            None => quote! { ::std::option::Option::None },
+        };
+        let (step_impl_generics, step_ty_generics, _) = step_generics.split_for_impl();
+        let rest_builder = quote! {
+            impl#step_impl_generics ::yew::html::RestPropsBuilder for #builder_name#step_ty_generics #where_clause {
+                fn __yew_rest(&mut self) -> ::std::option::Option<&mut ::yew::virtual_dom::Spread> {
+                    #rest
+                }
+            }
+
+            impl#step_impl_generics ::yew::html::RestProps for #builder_name#step_ty_generics #where_clause {}
+        };
+
         let builder = quote! {
             #(
                 #[doc(hidden)]
@@ -71,6 +89,8 @@ impl ToTokens for PropsBuilder<'_> {
 
             #impl_steps
 
+            #rest_builder
+
             impl#impl_generics #builder_name<#generic_args> #where_clause {
                 #[doc(hidden)]
                 #vis fn build(self) -> #props_name#ty_generics {
@@ -15,6 +15,7 @@ enum PropAttr {
     PropOr(Expr),
     PropOrElse(Expr),
     PropOrDefault,
+    Rest,
 }
 
 #[derive(Eq)]
@@ -30,6 +31,15 @@ impl PropField {
         matches!(self.attr, PropAttr::Required { .. })
     }
 
+    /// The rest field collects the attributes and listeners which aren't declared as props
+    pub fn is_rest(&self) -> bool {
+        matches!(self.attr, PropAttr::Rest)
+    }
+
+    pub fn name(&self) -> &Ident {
+        &self.name
+    }
+
     /// This step name is descriptive to help a developer realize they missed a required prop
     pub fn to_step_name(&self, props_name: &Ident) -> Ident {
         Ident::new(
@@ -55,7 +65,7 @@ impl PropField {
                     #name: ::std::option::Option::unwrap(self.wrapped.#wrapped_name),
                 }
             }
-            PropAttr::Option => {
+            PropAttr::Option | PropAttr::Rest => {
                 quote! {
                     #name: self.wrapped.#name,
                 }
@@ -83,7 +93,7 @@ impl PropField {
         let ty = &self.ty;
         let wrapped_name = self.wrapped_name();
         match &self.attr {
-            PropAttr::Option => {
+            PropAttr::Option | PropAttr::Rest => {
                 quote! {
                     #wrapped_name: #ty,
                 }
@@ -99,8 +109,13 @@ impl PropField {
     /// All optional props must implement the `Default` trait
     pub fn to_default_setter(&self) -> proc_macro2::TokenStream {
         let wrapped_name = self.wrapped_name();
-        quote! {
-            #wrapped_name: ::std::option::Option::None,
+        match &self.attr {
+            PropAttr::Rest => quote! {
+                #wrapped_name: ::std::default::Default::default(),
+            },
+            _ => quote! {
+                #wrapped_name: ::std::option::Option::None,
+            },
         }
     }
 
@@ -125,7 +140,7 @@ impl PropField {
                     }
                 }
             }
-            PropAttr::Option => {
+            PropAttr::Option | PropAttr::Rest => {
                 quote! {
                     #[doc(hidden)]
                     #vis fn #name(mut self, #name: impl ::yew::html::IntoPropValue<#ty>) -> #builder_name<#generic_arguments> {
@@ -149,13 +164,21 @@ impl PropField {
     // Detect Properties 2.0 attributes
     fn attribute(named_field: &Field) -> Result<PropAttr> {
         let attr = named_field.attrs.iter().find(|attr| {
-            attr.path.is_ident("prop_or")
+            attr.path.is_ident("prop")
+                || attr.path.is_ident("prop_or")
                 || attr.path.is_ident("prop_or_else")
                 || attr.path.is_ident("prop_or_default")
         });
 
         if let Some(attr) = attr {
-            if attr.path.is_ident("prop_or") {
+            if attr.path.is_ident("prop") {
+                let arg = attr.parse_args::<Ident>()?;
+                if arg == "rest" {
+                    Ok(PropAttr::Rest)
+                } else {
+                    Err(Error::new_spanned(arg, "expected `rest`"))
+                }
+            } else if attr.path.is_ident("prop_or") {
                 Ok(PropAttr::PropOr(attr.parse_args()?))
             } else if attr.path.is_ident("prop_or_else") {
                 Ok(PropAttr::PropOrElse(attr.parse_args()?))
@@ -31,6 +31,13 @@ impl Parse for DerivePropsInput {
                         .map(|f| f.try_into())
                         .collect::<Result<Vec<PropField>>>()?;
 
+                    if let Some(field) = prop_fields.iter().filter(|pf| pf.is_rest()).nth(1) {
+                        return Err(syn::Error::new_spanned(
+                            field.name(),
+                            "only one field can be marked with `#[prop(rest)]`",
+                        ));
+                    }
+
                     // Alphabetize
                     prop_fields.sort();
 
@@ -86,5 +93,27 @@ impl ToTokens for DerivePropsInput {
             }
         };
         tokens.extend(properties);
+
+        // Attributes and listeners which aren't declared as props are collected by the rest field
+        let rest = self.prop_fields.iter().find(|pf| pf.is_rest());
+        let rest_field = match rest {
+            Some(rest) => {
+                let name = rest.name();
+                quote! { ::std::option::Option::Some(&mut self.#name) }
+            }
+            None => quote! { ::std::option::Option::None },
+        };
+        tokens.extend(quote! {
+            impl#impl_generics ::yew::html::RestPropsBuilder for #props_name#ty_generics #where_clause {
+                fn __yew_rest(&mut self) -> ::std::option::Option<&mut ::yew::virtual_dom::Spread> {
+                    #rest_field
+                }
+            }
+        });
+        if rest.is_some() {
+            tokens.extend(quote! {
+                impl#impl_generics ::yew::html::HasRestField for #props_name#ty_generics #where_clause {}
+            });
+        }
     }
 }
@@ -0,0 +1,373 @@
+//! The `html_file!` macro reads the html from a template file instead of the macro input.
+//!
+//! The template is parsed like the input of `html!`. Syntax errors in it are reported with the
+//! path of the template and the line and column of the error, other errors point at the macro.
+
+use crate::html_tree::HtmlRootVNode;
+use proc_macro2::{Delimiter, Group, TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+use std::fs;
+use std::path::PathBuf;
+use syn::parse::{Parse, ParseStream};
+use syn::LitStr;
+
+pub struct HtmlFile {
+    path: PathBuf,
+    root: HtmlRootVNode,
+}
+
+impl Parse for HtmlFile {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let lit = input.parse::<LitStr>().map_err(|err| {
+            syn::Error::new(
+                err.span(),
+                "expected a string literal containing the template path",
+            )
+        })?;
+        if !input.is_empty() {
+            return Err(input.error("unexpected input after the template path"));
+        }
+
+        let relative_path = lit.value();
+        let path = resolve_path(&relative_path);
+        let source = fs::read_to_string(&path).map_err(|err| {
+            syn::Error::new(
+                lit.span(),
+                format!("failed to read `{}`: {}", path.display(), err),
+            )
+        })?;
+
+        let root = parse_template(&source).map_err(|errors| {
+            let mut errors = errors.into_iter().map(|(location, message)| {
+                let message = match location {
+                    Some(Location { line, column }) => {
+                        format!("{}:{}:{}: {}", relative_path, line, column + 1, message)
+                    }
+                    None => format!("{}: {}", relative_path, message),
+                };
+                syn::Error::new(lit.span(), message)
+            });
+            let mut error = errors.next().expect("at least one error");
+            error.extend(errors);
+            error
+        })?;
+
+        Ok(Self { path, root })
+    }
+}
+
+impl ToTokens for HtmlFile {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { path, root } = self;
+        let path = path.to_string_lossy();
+        // Including the template makes cargo rebuild the crate when it changes
+        tokens.extend(quote! {{
+            const _: &[u8] = ::std::include_bytes!(#path);
+            #root
+        }});
+    }
+}
+
+/// Resolves the path relative to the directory of the manifest of the crate being compiled.
+fn resolve_path(path: &str) -> PathBuf {
+    let manifest_dir = std::env::var_os("CARGO_MANIFEST_DIR").unwrap_or_default();
+    PathBuf::from(manifest_dir).join(path)
+}
+
+/// The line (1-based) and column (0-based, in characters) of an error in the template.
+#[derive(Clone, Copy, Debug)]
+struct Location {
+    line: usize,
+    column: usize,
+}
+
+impl Location {
+    fn of(source: &str, offset: usize) -> Self {
+        let before = &source[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count(),
+        }
+    }
+}
+
+type TemplateErrors = Vec<(Option<Location>, String)>;
+
+fn parse_template(source: &str) -> Result<HtmlRootVNode, TemplateErrors> {
+    let tokens = source.parse::<TokenStream>().map_err(|err| {
+        let location = unbalanced_offset(source).map(|offset| Location::of(source, offset));
+        vec![(location, err.to_string())]
+    })?;
+    syn::parse2(tokens.clone()).map_err(|err| locate_errors(source, tokens, err))
+}
+
+/// Finds the line and column of the errors of a template which failed to parse.
+///
+/// The tokens don't come from a source file, so their spans don't know where they are in the
+/// template. Instead the offset of every token is looked up in the source, and the template is
+/// parsed again up to a token: an error is located at the first token which makes the truncated
+/// template fail with the same error.
+fn locate_errors(source: &str, tokens: TokenStream, err: syn::Error) -> TemplateErrors {
+    let messages: Vec<_> = err.into_iter().map(|err| err.to_string()).collect();
+    let mut events = Vec::new();
+    flatten(source, tokens, &mut 0, &mut events);
+
+    messages
+        .iter()
+        .enumerate()
+        .map(|(index, message)| {
+            // Truncating the template can't tell where the input ends too early
+            if message.starts_with("unexpected end of input") {
+                return (
+                    Some(Location::of(source, source.trim_end().len())),
+                    message.clone(),
+                );
+            }
+
+            let occurrence = messages[..index].iter().filter(|m| *m == message).count();
+            let fails_with_error = |len: usize| {
+                syn::parse2::<HtmlRootVNode>(truncate(&events[..len])).map_or_else(
+                    |err| {
+                        err.into_iter()
+                            .filter(|err| err.to_string() == *message)
+                            .count()
+                            > occurrence
+                    },
+                    |_| false,
+                )
+            };
+
+            // The whole template fails with the error, so look for the shortest failing prefix
+            let (mut low, mut high) = (0, events.len());
+            while low + 1 < high {
+                let mid = (low + high) / 2;
+                if fails_with_error(mid) {
+                    high = mid;
+                } else {
+                    low = mid;
+                }
+            }
+            let location = tag_start(&events[..high])
+                .or_else(|| events.get(high.saturating_sub(1)))
+                .map(|(offset, _)| Location::of(source, *offset));
+            (location, message.clone())
+        })
+        .collect()
+}
+
+/// Errors about a tag are found when its `>` is parsed, but should point at its `<`.
+fn tag_start(events: &[(usize, Event)]) -> Option<&(usize, Event)> {
+    let is_punct = |event: &Event, c: char| matches!(event, Event::Token(TokenTree::Punct(punct)) if punct.as_char() == c);
+    let (last, rest) = events.split_last()?;
+    if !is_punct(&last.1, '>') {
+        return None;
+    }
+
+    // Groups are skipped, and so are the `<` and `>` of generics like `<Comp<T>>`
+    let (mut groups, mut angles) = (0, 0);
+    for entry in rest.iter().rev() {
+        match &entry.1 {
+            Event::Close => groups += 1,
+            Event::Open(_) if groups == 0 => return None,
+            Event::Open(_) => groups -= 1,
+            _ if groups > 0 => {}
+            event if is_punct(event, '>') => angles += 1,
+            event if is_punct(event, '<') && angles == 0 => return Some(entry),
+            event if is_punct(event, '<') => angles -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// A token of the template, with groups split into their delimiters.
+enum Event {
+    Open(Delimiter),
+    Token(TokenTree),
+    Close,
+}
+
+/// Appends the tokens with their offset in the source, starting the search at `offset`.
+fn flatten(
+    source: &str,
+    tokens: TokenStream,
+    offset: &mut usize,
+    events: &mut Vec<(usize, Event)>,
+) {
+    for token in tokens {
+        match token {
+            TokenTree::Group(group) => {
+                let (open, close) = match group.delimiter() {
+                    Delimiter::Parenthesis => ("(", ")"),
+                    Delimiter::Brace => ("{", "}"),
+                    Delimiter::Bracket => ("[", "]"),
+                    Delimiter::None => ("", ""),
+                };
+                events.push((find(source, offset, open), Event::Open(group.delimiter())));
+                flatten(source, group.stream(), offset, events);
+                events.push((find(source, offset, close), Event::Close));
+            }
+            token => {
+                let text = token.to_string();
+                events.push((find(source, offset, &text), Event::Token(token)));
+            }
+        }
+    }
+}
+
+/// Returns the offset of the next occurrence of `text` and moves `offset` past it.
+fn find(source: &str, offset: &mut usize, text: &str) -> usize {
+    let start = skip_trivia(source, *offset);
+    let found = if source[start..].starts_with(text) {
+        start
+    } else {
+        // Tokens like doc comments aren't written the way they're printed
+        source[start..].find(text).map_or(start, |i| start + i)
+    };
+    *offset = (found + text.len()).min(source.len());
+    found
+}
+
+/// Skips whitespace and comments.
+fn skip_trivia(source: &str, mut offset: usize) -> usize {
+    loop {
+        let rest = &source[offset..];
+        let trimmed = rest.trim_start();
+        offset += rest.len() - trimmed.len();
+        if trimmed.starts_with("//") {
+            offset += trimmed.find('\n').unwrap_or(trimmed.len());
+        } else if trimmed.starts_with("/*") {
+            offset += trimmed.find("*/").map_or(trimmed.len(), |i| i + 2);
+        } else {
+            return offset;
+        }
+    }
+}
+
+/// Rebuilds the tokens from the events, closing the groups which are still open at the end.
+fn truncate(events: &[(usize, Event)]) -> TokenStream {
+    let mut groups: Vec<(Option<Delimiter>, Vec<TokenTree>)> = vec![(None, Vec::new())];
+    for (_, event) in events {
+        match event {
+            Event::Open(delimiter) => groups.push((Some(*delimiter), Vec::new())),
+            Event::Token(token) => groups.last_mut().unwrap().1.push(token.clone()),
+            Event::Close => close_group(&mut groups),
+        }
+    }
+    while groups.len() > 1 {
+        close_group(&mut groups);
+    }
+    groups.pop().unwrap().1.into_iter().collect()
+}
+
+fn close_group(groups: &mut Vec<(Option<Delimiter>, Vec<TokenTree>)>) {
+    let (delimiter, tokens) = groups.pop().unwrap();
+    let group = Group::new(delimiter.unwrap(), tokens.into_iter().collect());
+    groups.last_mut().unwrap().1.push(TokenTree::Group(group));
+}
+
+/// Returns the offset of an unterminated literal or comment, or of an unbalanced delimiter.
+fn unbalanced_offset(source: &str) -> Option<usize> {
+    let bytes = source.as_bytes();
+    let mut open = Vec::new();
+    let mut offset = 0;
+    while offset < bytes.len() {
+        let start = offset;
+        match bytes[offset] {
+            b'/' if bytes.get(offset + 1) == Some(&b'/') => {
+                offset += source[offset..].find('\n').unwrap_or(source.len() - offset);
+            }
+            b'/' if bytes.get(offset + 1) == Some(&b'*') => match source[offset..].find("*/") {
+                Some(end) => offset += end + 1,
+                None => return Some(start),
+            },
+            b'"' => loop {
+                offset += 1;
+                match bytes.get(offset) {
+                    Some(b'\\') => offset += 1,
+                    Some(b'"') => break,
+                    Some(_) => {}
+                    None => return Some(start),
+                }
+            },
+            // Character literals, but not lifetimes
+            b'\'' if bytes.get(offset + 1) == Some(&b'\\') => {
+                match source[offset + 2..].find('\'') {
+                    Some(end) => offset += end + 2,
+                    None => return Some(start),
+                }
+            }
+            b'\'' if bytes.get(offset + 2) == Some(&b'\'') => offset += 2,
+            b'(' | b'[' | b'{' => open.push(offset),
+            close @ b')' | close @ b']' | close @ b'}' => match open.pop() {
+                Some(open)
+                    if matches!(
+                        (bytes[open], close),
+                        (b'(', b')') | (b'[', b']') | (b'{', b'}')
+                    ) => {}
+                _ => return Some(offset),
+            },
+            _ => {}
+        }
+        offset += 1;
+    }
+    open.pop()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn errors(source: &str) -> Vec<(usize, usize, String)> {
+        match parse_template(source) {
+            Ok(_) => panic!("expected the template to fail to parse"),
+            Err(errors) => errors
+                .into_iter()
+                .map(|(location, message)| {
+                    let location = location.expect("expected the location of the error");
+                    (location.line, location.column, message)
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn valid_templates_parse() {
+        assert!(parse_template("<div class=\"card\">\n    <h2>{ title }</h2>\n</div>\n").is_ok());
+    }
+
+    #[test]
+    fn errors_have_the_line_and_column() {
+        let errors = errors("<div>\n    <h2>{ \"title\" }</h3>\n</div>\n");
+        assert_eq!(errors.len(), 1);
+        let (line, column, message) = &errors[0];
+        assert_eq!((*line, *column), (2, 19));
+        assert_eq!(message, "this closing tag has no corresponding opening tag");
+    }
+
+    #[test]
+    fn lexing_errors_have_the_line() {
+        assert_eq!(errors("<div>\n    { \"title }\n</div>\n")[0].0, 2);
+    }
+
+    #[test]
+    fn unbalanced_delimiters_have_the_line_and_column() {
+        let errors = errors("<div>\n    { title ]\n</div>\n");
+        assert_eq!((errors[0].0, errors[0].1), (2, 12));
+    }
+
+    #[test]
+    fn comments_are_skipped() {
+        let errors = errors("// </h3>\n<div>\n    <h2>{ \"title\" }</h3>\n</div>\n");
+        assert_eq!((errors[0].0, errors[0].1), (3, 19));
+    }
+
+    #[test]
+    fn errors_at_the_end_point_at_the_last_line() {
+        let errors = errors("<div>\n    <h2 class=");
+        let (line, column, message) = &errors[0];
+        assert_eq!((*line, *column), (2, 14));
+        assert!(message.starts_with("unexpected end of input"));
+    }
+}
@@ -22,6 +22,13 @@ impl PeekValue<()> for HtmlBlock {
     }
 }
 
+impl HtmlBlock {
+    /// Returns `true` if the block only contains a literal.
+    pub fn is_literal(&self) -> bool {
+        matches!(&self.content, BlockContent::Node(node) if matches!(**node, HtmlNode::Literal(_)))
+    }
+}
+
 impl Parse for HtmlBlock {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let content;
@@ -26,6 +26,13 @@ impl PeekValue<()> for HtmlComponent {
     }
 }
 
+impl HtmlComponent {
+    /// Returns `true` if a `key` was given.
+    pub fn has_key(&self) -> bool {
+        self.props.special().key.is_some()
+    }
+}
+
 impl Parse for HtmlComponent {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         if HtmlComponentClose::peek(input.cursor()).is_some() {
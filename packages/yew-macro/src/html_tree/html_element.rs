@@ -1,14 +1,16 @@
-use super::{HtmlChildrenTree, HtmlDashedName, TagTokens};
+use super::lint::{did_you_mean, Lint};
+use super::{hoist, HtmlChildrenTree, HtmlDashedName, TagTokens};
 use crate::props::{ClassesForm, ElementProps, Prop};
 use crate::stringify::{Stringify, Value};
 use crate::{non_capitalized_ascii, Peek, PeekValue};
 use boolinator::Boolinator;
-use proc_macro2::{Delimiter, TokenStream};
+use proc_macro2::{Delimiter, Span, TokenStream};
 use quote::{quote, quote_spanned, ToTokens};
 use syn::buffer::Cursor;
 use syn::parse::{Parse, ParseStream};
 use syn::spanned::Spanned;
-use syn::{Block, Expr, Ident, Lit, LitStr, Token};
+use syn::{Block, Expr, ExprLit, Ident, Lit, LitStr, Token};
+use yew_validation::{aria, spec};
 
 pub struct HtmlElement {
     name: TagName,
@@ -24,6 +26,187 @@ impl PeekValue<()> for HtmlElement {
     }
 }
 
+impl HtmlElement {
+    /// Returns `true` if a `key` was given.
+    pub fn has_key(&self) -> bool {
+        self.props.key.is_some()
+    }
+
+    /// Returns the lowercase tag name if it's a literal.
+    fn literal_name(&self) -> Option<(String, Span)> {
+        match &self.name {
+            TagName::Lit(name) => Some((name.to_ascii_lowercase_string(), name.span())),
+            TagName::Expr(_) => None,
+        }
+    }
+
+    /// Returns the attribute with the given name.
+    fn attribute(&self, name: &str) -> Option<&Prop> {
+        self.props
+            .attributes
+            .iter()
+            .find(|prop| prop.label.to_string() == name)
+    }
+
+    /// Returns the lints for the element.
+    ///
+    /// Custom elements and other elements which aren't part of the spec are skipped, and
+    /// `lint="allow"` turns the lints off for a single element.
+    fn lints(&self) -> Vec<Lint> {
+        let (tag, span) = match self.literal_name() {
+            Some(name) if !self.props.allow_lints => name,
+            _ => return Vec::new(),
+        };
+        let mut lints = self.spec_lints(&tag);
+        lints.extend(self.accessibility_lints(&tag, span));
+        lints
+    }
+
+    /// Checks the attributes and children of the element against the HTML spec.
+    fn spec_lints(&self, tag: &str) -> Vec<Lint> {
+        let mut lints = Vec::new();
+
+        let ElementProps {
+            attributes,
+            booleans,
+            checked,
+            ..
+        } = &self.props;
+        for Prop { label, .. } in attributes.iter().chain(booleans).chain(checked) {
+            let attribute = label.to_string();
+            if !spec::is_allowed_attribute(tag, &attribute) {
+                lints.push(Lint::new(
+                    "invalid_html",
+                    label.span(),
+                    format!(
+                        "`{}` is not a valid attribute of `<{}>`{}",
+                        attribute,
+                        tag,
+                        did_you_mean(spec::suggest_attribute(tag, &attribute))
+                    ),
+                ));
+            }
+        }
+
+        for child in self.children.elements() {
+            let (child_tag, span) = match child.literal_name() {
+                Some(name) if !child.props.allow_lints => name,
+                _ => continue,
+            };
+            if let Err(err) = spec::check_child(tag, &child_tag) {
+                lints.push(Lint::new("invalid_html", span, err.to_string()));
+            }
+        }
+
+        lints
+    }
+
+    /// Checks the element for common accessibility issues.
+    ///
+    /// The checks for missing attributes and listeners are skipped if the element has a spread,
+    /// which might provide them.
+    fn accessibility_lints(&self, tag: &str, span: Span) -> Vec<Lint> {
+        let mut lints = Vec::new();
+        let has_spread = self.props.spread.is_some();
+
+        // https://www.w3.org/WAI/WCAG21/Understanding/non-text-content
+        let needs_alt = match tag {
+            "img" => true,
+            "area" => self.attribute("href").is_some(),
+            "input" => {
+                self.attribute("type")
+                    .and_then(|prop| lit_str_value(&prop.value))
+                    == Some("image".to_owned())
+            }
+            _ => false,
+        };
+        if needs_alt && !has_spread && self.attribute("alt").is_none() {
+            lints.push(Lint::accessibility(
+                span,
+                format!(
+                    "`<{}>` elements must have an `alt` attribute describing the image (hint: use `alt=\"\"` if the image is only decorative)",
+                    tag
+                ),
+            ));
+        }
+
+        for Prop { label, .. } in &self.props.attributes {
+            let attribute = label.to_string();
+            if attribute.starts_with("aria-") && !aria::is_valid_attribute(&attribute) {
+                lints.push(Lint::accessibility(
+                    label.span(),
+                    format!(
+                        "`{}` is not a valid ARIA attribute{}",
+                        attribute,
+                        did_you_mean(aria::suggest_attribute(&attribute))
+                    ),
+                ));
+            }
+        }
+
+        let role = self.attribute("role");
+        if let Some(prop) = role {
+            if let Some(value) = lit_str_value(&prop.value) {
+                if !aria::is_valid_role(&value) {
+                    lints.push(Lint::accessibility(
+                        prop.value.span(),
+                        format!(
+                            "`{}` is not a valid ARIA role{}",
+                            value,
+                            did_you_mean(
+                                value
+                                    .split_ascii_whitespace()
+                                    .find(|role| !aria::is_valid_role(role))
+                                    .and_then(aria::suggest_role)
+                            )
+                        ),
+                    ));
+                }
+            }
+        }
+
+        // https://www.w3.org/WAI/WCAG21/Understanding/keyboard
+        let listener = |name: &str| {
+            self.props
+                .listeners
+                .iter()
+                .find(|prop| prop.label.to_string() == name)
+        };
+        let is_static = !has_spread
+            && spec::element_spec(tag).is_some()
+            && !spec::is_interactive_content(tag)
+            && !matches!(
+                tag,
+                "area" | "audio" | "body" | "html" | "option" | "summary" | "video"
+            );
+        if let Some(onclick) = listener("onclick").filter(|_| is_static) {
+            if role.is_none() {
+                lints.push(Lint::accessibility(
+                    onclick.label.span(),
+                    format!(
+                        "clickable `<{}>` elements must have a `role` (hint: use a `<button>` instead or add `role=\"button\"`)",
+                        tag
+                    ),
+                ));
+            }
+            if ["onkeydown", "onkeyup", "onkeypress"]
+                .iter()
+                .all(|name| listener(name).is_none())
+            {
+                lints.push(Lint::accessibility(
+                    onclick.label.span(),
+                    format!(
+                        "clickable `<{}>` elements must also be usable with a keyboard (hint: use a `<button>` instead or add an `onkeydown` listener)",
+                        tag
+                    ),
+                ));
+            }
+        }
+
+        lints
+    }
+}
+
 impl Parse for HtmlElement {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         if HtmlElementClose::peek(input.cursor()).is_some() {
@@ -89,13 +272,93 @@ impl Parse for HtmlElement {
 }
 
 impl ToTokens for HtmlElement {
-    #[allow(clippy::cognitive_complexity)]
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        if self.is_static() {
+            let (_, span) = self
+                .literal_name()
+                .expect("static elements have a literal name");
+            tokens.extend(hoist(span, self.to_unhoisted_tokens()));
+        } else {
+            self.expand(tokens, false);
+        }
+    }
+}
+
+impl HtmlElement {
+    /// Returns `true` if neither the element nor its children depend on an expression, so it can
+    /// be built once and reused.
+    ///
+    /// Elements with listeners, a `ref` or a `key` aren't static, and neither are `<input>` and
+    /// `<textarea>` elements because their value is reset on every render. Elements with lints
+    /// aren't hoisted either so that the warnings point at the element.
+    pub fn is_static(&self) -> bool {
+        let ElementProps {
+            attributes,
+            listeners,
+            classes,
+            booleans,
+            value,
+            checked,
+            node_ref,
+            key,
+            spread,
+            ..
+        } = &self.props;
+
+        let is_static_tag = match self.literal_name() {
+            Some((name, _)) => name != "input" && name != "textarea",
+            None => false,
+        };
+        let is_static_class = match classes {
+            Some(ClassesForm::Single(classes)) => classes.try_into_lit().is_some(),
+            Some(ClassesForm::Tuple(_)) => false,
+            None => true,
+        };
+
+        is_static_tag
+            && is_static_class
+            && listeners.is_empty()
+            && value.is_none()
+            && checked.is_none()
+            && node_ref.is_none()
+            && key.is_none()
+            && spread.is_none()
+            && attributes
+                .iter()
+                .all(|attr| attr.value.try_into_lit().is_some())
+            && booleans.iter().all(|attr| {
+                matches!(
+                    &attr.value,
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Bool(_),
+                        ..
+                    })
+                )
+            })
+            && self.lints().is_empty()
+            && self.children.is_static()
+    }
+
+    /// Returns the tokens of the element without hoisting it, which is used inside of a hoisted
+    /// subtree.
+    pub fn to_unhoisted_tokens(&self) -> TokenStream {
+        let mut tokens = TokenStream::new();
+        self.expand(&mut tokens, true);
+        tokens
+    }
+
+    #[allow(clippy::cognitive_complexity)]
+    fn expand(&self, tokens: &mut TokenStream, hoisted: bool) {
         let Self {
             name,
             props,
             children,
         } = self;
+        let children = if hoisted {
+            children.to_unhoisted_tokens()
+        } else {
+            children.to_token_stream()
+        };
 
         let ElementProps {
             classes,
@@ -106,6 +369,7 @@ impl ToTokens for HtmlElement {
             node_ref,
             key,
             listeners,
+            ..
         } = &props;
 
         // attributes with special treatment
@@ -268,6 +532,18 @@ impl ToTokens for HtmlElement {
             })
         };
 
+        let lints = self.lints();
+        let attributes = if lints.is_empty() {
+            attributes
+        } else {
+            quote! {
+                {
+                    #(#lints)*
+                    #attributes
+                }
+            }
+        };
+
         let listeners = if listeners.is_empty() {
             quote! { ::yew::virtual_dom::listeners::Listeners::None }
         } else {
@@ -294,53 +570,65 @@ impl ToTokens for HtmlElement {
             )
         };
 
+        // spread attributes are merged after the element has been created so that the
+        // `Attributes::Static` fast path is only lost for elements which use them
+        let spread = |vtag: TokenStream| match &props.spread {
+            Some(expr) => quote_spanned! {expr.span()=>
+                ::yew::virtual_dom::VTag::__macro_spread(#vtag, #expr)
+            },
+            None => vtag,
+        };
+
         tokens.extend(match &name {
             TagName::Lit(name) => {
                 let name_span = name.span();
                 let name = name.to_ascii_lowercase_string();
                 match &*name {
                     "input" => {
+                        let vtag = spread(quote_spanned! {name_span=>
+                            ::yew::virtual_dom::VTag::__new_input(
+                                #value,
+                                #checked,
+                                #node_ref,
+                                #key,
+                                #attributes,
+                                #listeners,
+                            )
+                        });
                         quote_spanned! {name_span=>
                             #[allow(clippy::redundant_clone, unused_braces)]
-                            ::std::convert::Into::<::yew::virtual_dom::VNode>::into(
-                                ::yew::virtual_dom::VTag::__new_input(
-                                    #value,
-                                    #checked,
-                                    #node_ref,
-                                    #key,
-                                    #attributes,
-                                    #listeners,
-                                ),
-                            )
+                            ::std::convert::Into::<::yew::virtual_dom::VNode>::into(#vtag)
                         }
                     }
                     "textarea" => {
+                        let vtag = spread(quote_spanned! {name_span=>
+                            ::yew::virtual_dom::VTag::__new_textarea(
+                                #value,
+                                #node_ref,
+                                #key,
+                                #attributes,
+                                #listeners,
+                            )
+                        });
                         quote_spanned! {name_span=>
                             #[allow(clippy::redundant_clone, unused_braces)]
-                            ::std::convert::Into::<::yew::virtual_dom::VNode>::into(
-                                ::yew::virtual_dom::VTag::__new_textarea(
-                                    #value,
-                                    #node_ref,
-                                    #key,
-                                    #attributes,
-                                    #listeners,
-                                ),
-                            )
+                            ::std::convert::Into::<::yew::virtual_dom::VNode>::into(#vtag)
                         }
                     }
                     _ => {
+                        let vtag = spread(quote_spanned! {name_span=>
+                            ::yew::virtual_dom::VTag::__new_other(
+                                ::std::borrow::Cow::<'static, str>::Borrowed(#name),
+                                #node_ref,
+                                #key,
+                                #attributes,
+                                #listeners,
+                                #child_list,
+                            )
+                        });
                         quote_spanned! {name_span=>
                             #[allow(clippy::redundant_clone, unused_braces)]
-                            ::std::convert::Into::<::yew::virtual_dom::VNode>::into(
-                                ::yew::virtual_dom::VTag::__new_other(
-                                    ::std::borrow::Cow::<'static, str>::Borrowed(#name),
-                                    #node_ref,
-                                    #key,
-                                    #attributes,
-                                    #listeners,
-                                    #child_list,
-                                ),
-                            )
+                            ::std::convert::Into::<::yew::virtual_dom::VNode>::into(#vtag)
                         }
                     }
                 }
@@ -351,6 +639,12 @@ impl ToTokens for HtmlElement {
                 let expr = &name.expr;
                 let vtag_name = Ident::new("__yew_vtag_name", expr.span());
 
+                let spread_dynamic = props.spread.as_ref().map(|expr| {
+                    quote_spanned! {expr.span()=>
+                        #vtag = ::yew::virtual_dom::VTag::__macro_spread(#vtag, #expr);
+                    }
+                });
+
                 // handle special attribute value
                 let handle_value_attr = props.value.as_ref().map(|prop| {
                     let v = prop.value.optimize_literals();
@@ -409,6 +703,7 @@ impl ToTokens for HtmlElement {
                             __yew_vtag
                         }
                     };
+                    #spread_dynamic
 
                     // These are the runtime-checks exclusive to dynamic tags.
                     // For literal tags this is already done at compile-time.
@@ -439,6 +734,15 @@ impl ToTokens for HtmlElement {
     }
 }
 
+fn lit_str_value(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(lit), ..
+        }) => Some(lit.value()),
+        _ => None,
+    }
+}
+
 fn wrap_attr_prop(prop: &Prop) -> TokenStream {
     let value = prop.value.optimize_literals();
     quote_spanned! {value.span()=>
@@ -0,0 +1,151 @@
+use super::lint::Lint;
+use super::{HtmlRootBraced, ToNodeIterator};
+use crate::PeekValue;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{quote_spanned, ToTokens};
+use syn::buffer::Cursor;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{Expr, Pat, PatOr, Token};
+
+/// A `for` loop which renders its body for every item.
+///
+/// ```text
+/// for item in items { <li key={item.id}>{ &item.name }</li> }
+/// ```
+///
+/// This is different from `for iter`, which renders the items of an iterator.
+pub struct HtmlFor {
+    for_token: Token![for],
+    pat: Pat,
+    in_token: Token![in],
+    expr: Box<Expr>,
+    body: HtmlRootBraced,
+}
+
+/// Parses a pattern which may contain top-level alternatives, like `A | B`.
+pub fn parse_pat(input: ParseStream) -> syn::Result<Pat> {
+    let leading_vert: Option<Token![|]> = input.parse()?;
+    let pat: Pat = input.parse()?;
+    let has_alternatives = input.peek(Token![|]) && !input.peek(Token![||]);
+    if leading_vert.is_none() && !has_alternatives {
+        return Ok(pat);
+    }
+
+    let mut cases = Punctuated::new();
+    cases.push_value(pat);
+    while input.peek(Token![|]) && !input.peek(Token![||]) {
+        cases.push_punct(input.parse()?);
+        cases.push_value(input.parse()?);
+    }
+    Ok(Pat::Or(PatOr {
+        attrs: Vec::new(),
+        leading_vert,
+        cases,
+    }))
+}
+
+impl PeekValue<()> for HtmlFor {
+    fn peek(cursor: Cursor) -> Option<()> {
+        let (ident, _) = cursor.ident()?;
+        if ident != "for" {
+            return None;
+        }
+
+        // `for iter` renders an iterator, `for pat in iter { ... }` is a loop
+        syn::parse::Parser::parse2(
+            |input: ParseStream| {
+                input.parse::<Token![for]>()?;
+                parse_pat(input)?;
+                input.parse::<Token![in]>()?;
+                input.parse::<TokenStream>()
+            },
+            cursor.token_stream(),
+        )
+        .ok()
+        .map(|_| ())
+    }
+}
+
+impl Parse for HtmlFor {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let for_token = input.parse()?;
+        let pat = parse_pat(input)?;
+        let in_token = input.parse()?;
+        let expr = Box::new(input.call(Expr::parse_without_eager_brace)?);
+        if input.is_empty() {
+            return Err(syn::Error::new_spanned(
+                expr,
+                "expected a block of html after the iterator",
+            ));
+        }
+        let body: HtmlRootBraced = input.parse()?;
+
+        Ok(Self {
+            for_token,
+            pat,
+            in_token,
+            expr,
+            body,
+        })
+    }
+}
+
+impl HtmlFor {
+    /// Warns about children of the body that should have a key but don't.
+    fn missing_key_warnings(&self) -> TokenStream {
+        self.body
+            .children
+            .0
+            .iter()
+            .filter(|child| child.has_key() == Some(false))
+            .map(|child| {
+                Lint::new(
+                    "missing_key_in_for_loop",
+                    child.span(),
+                    "elements rendered in a `for` loop should have a unique `key` so that they can be updated efficiently (hint: add `key={...}`)",
+                )
+                .into_token_stream()
+            })
+            .collect()
+    }
+}
+
+impl ToTokens for HtmlFor {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let nodes = self.to_node_iterator_stream();
+        tokens.extend(quote_spanned! {self.for_token.span()=>
+            ::yew::virtual_dom::VNode::VList(
+                ::yew::virtual_dom::VList::with_children(#nodes, ::std::option::Option::None)
+            )
+        });
+    }
+}
+
+impl ToNodeIterator for HtmlFor {
+    fn to_node_iterator_stream(&self) -> Option<TokenStream> {
+        let Self {
+            for_token,
+            pat,
+            in_token,
+            expr,
+            body,
+        } = self;
+
+        let vec_ident = Ident::new("__yew_for_nodes", Span::call_site());
+        let children = body.children.to_build_vec_token_stream();
+        let warnings = self.missing_key_warnings();
+
+        Some(quote_spanned! {for_token.span()=>
+            {
+                let mut #vec_ident = ::std::vec::Vec::new();
+                #for_token #pat #in_token #expr {
+                    #warnings
+                    ::std::iter::Extend::extend(&mut #vec_ident, #children);
+                }
+                #vec_ident
+            }
+        })
+    }
+}
@@ -0,0 +1,111 @@
+use super::{HtmlRootBraced, ToNodeIterator};
+use crate::PeekValue;
+use boolinator::Boolinator;
+use proc_macro2::TokenStream;
+use quote::{quote_spanned, ToTokens};
+use syn::buffer::Cursor;
+use syn::parse::{Parse, ParseStream};
+use syn::spanned::Spanned;
+use syn::token::Brace;
+use syn::{Expr, Token};
+
+/// An `if` or `if let` expression with html branches.
+///
+/// ```text
+/// if cond { <a/> } else if let Some(x) = y { <b/> } else { <c/> }
+/// ```
+pub struct HtmlIf {
+    if_token: Token![if],
+    cond: Box<Expr>,
+    then_branch: HtmlRootBraced,
+    else_branch: Option<(Token![else], Box<HtmlElse>)>,
+}
+
+enum HtmlElse {
+    If(HtmlIf),
+    Tree(HtmlRootBraced),
+}
+
+impl PeekValue<()> for HtmlIf {
+    fn peek(cursor: Cursor) -> Option<()> {
+        let (ident, _) = cursor.ident()?;
+        (ident == "if").as_option()
+    }
+}
+
+impl Parse for HtmlIf {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let if_token = input.parse()?;
+        if input.peek(Brace) {
+            return Err(syn::Error::new_spanned(
+                if_token,
+                "missing condition for `if` expression",
+            ));
+        }
+        let cond = Box::new(input.call(Expr::parse_without_eager_brace)?);
+        if input.is_empty() {
+            return Err(syn::Error::new_spanned(
+                cond,
+                "expected a block of html after the condition",
+            ));
+        }
+        let then_branch = input.parse()?;
+
+        let else_branch = if input.peek(Token![else]) {
+            let else_token: Token![else] = input.parse()?;
+            if input.is_empty() {
+                return Err(syn::Error::new_spanned(
+                    else_token,
+                    "expected `if` or a block of html after `else`",
+                ));
+            }
+            let branch = if HtmlIf::peek(input.cursor()).is_some() {
+                HtmlElse::If(input.parse()?)
+            } else {
+                HtmlElse::Tree(input.parse()?)
+            };
+            Some((else_token, Box::new(branch)))
+        } else {
+            None
+        };
+
+        Ok(Self {
+            if_token,
+            cond,
+            then_branch,
+            else_branch,
+        })
+    }
+}
+
+impl ToTokens for HtmlIf {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self {
+            if_token,
+            cond,
+            then_branch,
+            else_branch,
+        } = self;
+
+        let else_branch = match else_branch {
+            Some((_, branch)) => match &**branch {
+                HtmlElse::If(html_if) => html_if.to_token_stream(),
+                HtmlElse::Tree(tree) => tree.to_token_stream(),
+            },
+            None => quote_spanned! {if_token.span()=>
+                ::yew::virtual_dom::VNode::VList(::yew::virtual_dom::VList::new())
+            },
+        };
+
+        tokens.extend(quote_spanned! {if_token.span()=>
+            if #cond { #then_branch } else { #else_branch }
+        });
+    }
+}
+
+impl ToNodeIterator for HtmlIf {
+    fn to_node_iterator_stream(&self) -> Option<TokenStream> {
+        // An `if` always produces exactly one node
+        None
+    }
+}
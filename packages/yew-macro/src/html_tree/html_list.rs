@@ -1,6 +1,7 @@
-use super::{html_dashed_name::HtmlDashedName, HtmlChildrenTree, TagTokens};
+use super::{hoist, html_dashed_name::HtmlDashedName, HtmlChildrenTree, TagTokens};
 use crate::{props::Prop, Peek, PeekValue};
 use boolinator::Boolinator;
+use proc_macro2::Span;
 use quote::{quote, quote_spanned, ToTokens};
 use syn::buffer::Cursor;
 use syn::parse::{Parse, ParseStream};
@@ -21,6 +22,49 @@ impl PeekValue<()> for HtmlList {
     }
 }
 
+impl HtmlList {
+    /// Returns `true` if a `key` was given.
+    pub fn has_key(&self) -> bool {
+        self.open.props.key.is_some()
+    }
+
+    pub(super) fn children(&self) -> &HtmlChildrenTree {
+        &self.children
+    }
+
+    /// Returns `true` if the fragment has no `key` and all of its children are static.
+    pub fn is_static(&self) -> bool {
+        !self.has_key() && self.children.is_static()
+    }
+
+    /// Returns the tokens of the fragment without hoisting it, which is used inside of a hoisted
+    /// subtree.
+    pub fn to_unhoisted_tokens(&self) -> proc_macro2::TokenStream {
+        self.expand(self.children.to_unhoisted_tokens())
+    }
+
+    /// Returns the span of the opening and closing fragment.
+    fn span(&self) -> Span {
+        let open = self.open.to_spanned();
+        let close = self.close.to_spanned();
+        quote! { #open#close }.span()
+    }
+
+    fn expand(&self, children: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let key = if let Some(key) = &self.open.props.key {
+            quote_spanned! {key.span()=> ::std::option::Option::Some(::std::convert::Into::<::yew::virtual_dom::Key>::into(#key))}
+        } else {
+            quote! { ::std::option::Option::None }
+        };
+
+        quote_spanned! {self.span()=>
+            ::yew::virtual_dom::VNode::VList(
+                ::yew::virtual_dom::VList::with_children(#children, #key)
+            )
+        }
+    }
+}
+
 impl Parse for HtmlList {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         if HtmlListClose::peek(input.cursor()).is_some() {
@@ -57,28 +101,10 @@ impl Parse for HtmlList {
 
 impl ToTokens for HtmlList {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        let Self {
-            open,
-            children,
-            close,
-        } = &self;
-
-        let key = if let Some(key) = &open.props.key {
-            quote_spanned! {key.span()=> ::std::option::Option::Some(::std::convert::Into::<::yew::virtual_dom::Key>::into(#key))}
+        tokens.extend(if self.is_static() {
+            hoist(self.span(), self.to_unhoisted_tokens())
         } else {
-            quote! { ::std::option::Option::None }
-        };
-
-        let spanned = {
-            let open = open.to_spanned();
-            let close = close.to_spanned();
-            quote! { #open#close }
-        };
-
-        tokens.extend(quote_spanned! {spanned.span()=>
-            ::yew::virtual_dom::VNode::VList(
-                ::yew::virtual_dom::VList::with_children(#children, #key)
-            )
+            self.expand(self.children.to_token_stream())
         });
     }
 }
@@ -0,0 +1,149 @@
+use super::html_for::parse_pat;
+use super::{HtmlRootBraced, HtmlTree, ToNodeIterator};
+use crate::PeekValue;
+use boolinator::Boolinator;
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned, ToTokens};
+use syn::buffer::Cursor;
+use syn::parse::{Parse, ParseStream};
+use syn::spanned::Spanned;
+use syn::token::Brace;
+use syn::{braced, Expr, Pat, Token};
+
+/// A `match` expression with html arms.
+///
+/// ```text
+/// match value {
+///     Some(x) if x > 1 => <a/>,
+///     Some(_) => { <b/> <c/> }
+///     None => <></>,
+/// }
+/// ```
+pub struct HtmlMatch {
+    match_token: Token![match],
+    expr: Box<Expr>,
+    arms: Vec<HtmlMatchArm>,
+}
+
+struct HtmlMatchArm {
+    pat: Pat,
+    guard: Option<(Token![if], Box<Expr>)>,
+    fat_arrow: Token![=>],
+    body: HtmlArmBody,
+}
+
+enum HtmlArmBody {
+    Braced(HtmlRootBraced),
+    Tree(HtmlTree),
+}
+
+impl PeekValue<()> for HtmlMatch {
+    fn peek(cursor: Cursor) -> Option<()> {
+        let (ident, _) = cursor.ident()?;
+        (ident == "match").as_option()
+    }
+}
+
+impl Parse for HtmlMatch {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let match_token = input.parse()?;
+        let expr = Box::new(input.call(Expr::parse_without_eager_brace)?);
+        if !input.peek(Brace) {
+            return Err(syn::Error::new_spanned(
+                expr,
+                "expected the arms of the `match` in braces after the expression",
+            ));
+        }
+
+        let content;
+        braced!(content in input);
+        let mut arms = Vec::new();
+        while !content.is_empty() {
+            arms.push(content.parse()?);
+        }
+
+        Ok(Self {
+            match_token,
+            expr,
+            arms,
+        })
+    }
+}
+
+impl Parse for HtmlMatchArm {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let pat = parse_pat(input)?;
+        let guard = if input.peek(Token![if]) {
+            let if_token = input.parse()?;
+            Some((if_token, Box::new(input.parse()?)))
+        } else {
+            None
+        };
+        let fat_arrow = input.parse()?;
+
+        let body = if input.peek(Brace) {
+            HtmlArmBody::Braced(input.parse()?)
+        } else if HtmlTree::peek_html_type(input).is_some() {
+            HtmlArmBody::Tree(input.parse()?)
+        } else {
+            return Err(input.error(
+                "expected html for the match arm (hint: use `{ ... }` to render an expression)",
+            ));
+        };
+
+        // The comma is optional after braced arms
+        let comma: Option<Token![,]> = input.parse()?;
+        if comma.is_none() && !input.is_empty() && !matches!(body, HtmlArmBody::Braced(_)) {
+            return Err(input.error("expected `,` after the match arm"));
+        }
+
+        Ok(Self {
+            pat,
+            guard,
+            fat_arrow,
+            body,
+        })
+    }
+}
+
+impl ToTokens for HtmlMatch {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self {
+            match_token,
+            expr,
+            arms,
+        } = self;
+
+        let arms = arms.iter().map(|arm| {
+            let HtmlMatchArm {
+                pat,
+                guard,
+                fat_arrow,
+                body,
+            } = arm;
+            let guard = guard
+                .as_ref()
+                .map(|(if_token, guard)| quote! { #if_token #guard });
+            let body = match body {
+                HtmlArmBody::Braced(tree) => tree.to_token_stream(),
+                HtmlArmBody::Tree(tree) => quote_spanned! {tree.span()=>
+                    ::std::convert::Into::<::yew::virtual_dom::VNode>::into(#tree)
+                },
+            };
+            quote! { #pat #guard #fat_arrow { #body } }
+        });
+
+        tokens.extend(quote_spanned! {match_token.span()=>
+            match #expr {
+                #(#arms)*
+            }
+        });
+    }
+}
+
+impl ToNodeIterator for HtmlMatch {
+    fn to_node_iterator_stream(&self) -> Option<TokenStream> {
+        // A `match` always produces exactly one node
+        None
+    }
+}
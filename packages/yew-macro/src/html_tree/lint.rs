@@ -0,0 +1,74 @@
+//! Warnings about html which compiles but probably doesn't do what was intended.
+
+use proc_macro2::{Span, TokenStream};
+use quote::{quote_spanned, ToTokens};
+use syn::Ident;
+
+/// A warning emitted by `html!`.
+///
+/// Proc macros can't emit warnings on stable so this expands to a call to a deprecated function.
+/// The name of the function and the deprecation note make up the warning.
+pub struct Lint {
+    name: &'static str,
+    span: Span,
+    message: String,
+    deny: bool,
+}
+
+impl Lint {
+    pub fn new(name: &'static str, span: Span, message: impl Into<String>) -> Self {
+        Self {
+            name,
+            span,
+            message: message.into(),
+            deny: false,
+        }
+    }
+
+    /// Creates an accessibility lint.
+    ///
+    /// These are errors instead of warnings when the `a11y_errors` feature is enabled.
+    pub fn accessibility(span: Span, message: impl Into<String>) -> Self {
+        Self {
+            deny: cfg!(feature = "a11y_errors"),
+            ..Self::new("inaccessible_html", span, message)
+        }
+    }
+}
+
+impl ToTokens for Lint {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self {
+            name,
+            span,
+            message,
+            deny,
+        } = self;
+        if *deny {
+            tokens.extend(quote_spanned! {*span=> ::std::compile_error!(#message);});
+            return;
+        }
+
+        let name = Ident::new(name, *span);
+        // Every lint gets its own block so that the same lint can be emitted more than once
+        tokens.extend(quote_spanned! {*span=>
+            {
+                #[deprecated(note = #message)]
+                fn #name() {}
+
+                if false {
+                    #name();
+                };
+            }
+        });
+    }
+}
+
+/// Formats a hint which suggests `suggestion` in place of a name that wasn't recognised.
+///
+/// Returns an empty string if there's nothing to suggest so it can be appended to any message.
+pub fn did_you_mean(suggestion: Option<&str>) -> String {
+    suggestion
+        .map(|suggestion| format!(" (hint: did you mean `{}`?)", suggestion))
+        .unwrap_or_default()
+}
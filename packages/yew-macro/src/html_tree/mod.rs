@@ -4,23 +4,30 @@ use quote::{quote, quote_spanned, ToTokens};
 use syn::ext::IdentExt;
 use syn::parse::{Parse, ParseStream, Result};
 use syn::spanned::Spanned;
-use syn::Token;
+use syn::{braced, token, Token};
 
 mod html_block;
 mod html_component;
 mod html_dashed_name;
 mod html_element;
+mod html_for;
+mod html_if;
 mod html_iterable;
 mod html_list;
+mod html_match;
 mod html_node;
+mod lint;
 mod tag;
 
 use html_block::HtmlBlock;
 use html_component::HtmlComponent;
 pub use html_dashed_name::HtmlDashedName;
 use html_element::HtmlElement;
+use html_for::HtmlFor;
+use html_if::HtmlIf;
 use html_iterable::HtmlIterable;
 use html_list::HtmlList;
+use html_match::HtmlMatch;
 use html_node::HtmlNode;
 use tag::TagTokens;
 
@@ -29,6 +36,9 @@ pub enum HtmlType {
     Component,
     List,
     Element,
+    If,
+    Match,
+    For,
     Empty,
 }
 
@@ -37,6 +47,9 @@ pub enum HtmlTree {
     Component(Box<HtmlComponent>),
     List(Box<HtmlList>),
     Element(Box<HtmlElement>),
+    If(Box<HtmlIf>),
+    Match(Box<HtmlMatch>),
+    For(Box<HtmlFor>),
     Empty,
 }
 
@@ -50,6 +63,9 @@ impl Parse for HtmlTree {
             HtmlType::Element => HtmlTree::Element(Box::new(input.parse()?)),
             HtmlType::Block => HtmlTree::Block(Box::new(input.parse()?)),
             HtmlType::List => HtmlTree::List(Box::new(input.parse()?)),
+            HtmlType::If => HtmlTree::If(Box::new(input.parse()?)),
+            HtmlType::Match => HtmlTree::Match(Box::new(input.parse()?)),
+            HtmlType::For => HtmlTree::For(Box::new(input.parse()?)),
         };
         Ok(html_tree)
     }
@@ -65,6 +81,12 @@ impl HtmlTree {
 
         if input.is_empty() {
             Some(HtmlType::Empty)
+        } else if HtmlIf::peek(input.cursor()).is_some() {
+            Some(HtmlType::If)
+        } else if HtmlMatch::peek(input.cursor()).is_some() {
+            Some(HtmlType::Match)
+        } else if HtmlFor::peek(input.cursor()).is_some() {
+            Some(HtmlType::For)
         } else if input
             .cursor()
             .group(proc_macro2::Delimiter::Brace)
@@ -103,6 +125,52 @@ impl HtmlTree {
             None
         }
     }
+
+    /// Returns whether the node has a `key`, or `None` if it can't have one.
+    fn has_key(&self) -> Option<bool> {
+        match self {
+            HtmlTree::Component(comp) => Some(comp.has_key()),
+            HtmlTree::Element(element) => Some(element.has_key()),
+            HtmlTree::List(list) => Some(list.has_key()),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the node doesn't contain any expressions, listeners or components.
+    fn is_static(&self) -> bool {
+        match self {
+            HtmlTree::Element(element) => element.is_static(),
+            HtmlTree::List(list) => list.is_static(),
+            HtmlTree::Block(block) => block.is_literal(),
+            _ => false,
+        }
+    }
+
+    /// Returns the tokens of a static node without hoisting it, which is used inside of a hoisted
+    /// subtree.
+    fn to_unhoisted_tokens(&self) -> TokenStream {
+        match self {
+            HtmlTree::Element(element) => element.to_unhoisted_tokens(),
+            HtmlTree::List(list) => list.to_unhoisted_tokens(),
+            tree => tree.to_token_stream(),
+        }
+    }
+}
+
+/// Builds a static subtree once per thread and clones it on every render.
+///
+/// Clones of the same subtree share a marker, which lets the diff keep the rendered subtree as it
+/// is instead of comparing it.
+fn hoist(span: Span, node: TokenStream) -> TokenStream {
+    quote_spanned! {span=>
+        {
+            ::std::thread_local! {
+                static __YEW_HOISTED: ::yew::virtual_dom::VNode =
+                    ::yew::virtual_dom::VNode::__hoist(#node);
+            }
+            __YEW_HOISTED.with(::yew::virtual_dom::VNode::__clone_hoisted)
+        }
+    }
 }
 
 impl ToTokens for HtmlTree {
@@ -115,6 +183,9 @@ impl ToTokens for HtmlTree {
             HtmlTree::Element(tag) => tag.to_tokens(tokens),
             HtmlTree::List(list) => list.to_tokens(tokens),
             HtmlTree::Block(block) => block.to_tokens(tokens),
+            HtmlTree::If(html_if) => html_if.to_tokens(tokens),
+            HtmlTree::Match(html_match) => html_match.to_tokens(tokens),
+            HtmlTree::For(html_for) => html_for.to_tokens(tokens),
         }
     }
 }
@@ -174,6 +245,45 @@ impl ToTokens for HtmlRootVNode {
     }
 }
 
+/// The html inside the braces of a control flow expression, like the branches of an `if`.
+///
+/// It may contain any number of children, which are wrapped in a `VList` unless there's exactly
+/// one.
+pub struct HtmlRootBraced {
+    brace: token::Brace,
+    children: HtmlChildrenTree,
+}
+
+impl Parse for HtmlRootBraced {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let content;
+        let brace = braced!(content in input);
+        let mut children = HtmlChildrenTree::new();
+        while !content.is_empty() {
+            children.parse_child(&content)?;
+        }
+
+        Ok(Self { brace, children })
+    }
+}
+
+impl ToTokens for HtmlRootBraced {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { brace, children } = self;
+
+        tokens.extend(match children.0.as_slice() {
+            [child] if children.only_single_node_children() => quote_spanned! {brace.span=>
+                ::std::convert::Into::<::yew::virtual_dom::VNode>::into(#child)
+            },
+            _ => quote_spanned! {brace.span=>
+                ::yew::virtual_dom::VNode::VList(
+                    ::yew::virtual_dom::VList::with_children(#children, ::std::option::Option::None)
+                )
+            },
+        });
+    }
+}
+
 /// This trait represents a type that can be unfolded into multiple html nodes.
 pub trait ToNodeIterator {
     /// Generate a token stream which produces a value that implements IntoIterator<Item=T> where T is inferred by the compiler.
@@ -186,6 +296,7 @@ impl ToNodeIterator for HtmlTree {
     fn to_node_iterator_stream(&self) -> Option<TokenStream> {
         match self {
             HtmlTree::Block(block) => block.to_node_iterator_stream(),
+            HtmlTree::For(html_for) => html_for.to_node_iterator_stream(),
             // everything else is just a single node.
             _ => None,
         }
@@ -208,6 +319,35 @@ impl HtmlChildrenTree {
         self.0.is_empty()
     }
 
+    /// Returns `true` if all children are static.
+    pub fn is_static(&self) -> bool {
+        self.0.iter().all(HtmlTree::is_static)
+    }
+
+    /// Builds the children of a hoisted subtree, which are all static.
+    pub fn to_unhoisted_tokens(&self) -> TokenStream {
+        let children = self.0.iter().map(|child| {
+            let child = child.to_unhoisted_tokens();
+            quote! { ::std::convert::Into::into(#child) }
+        });
+        quote! {
+            ::std::vec![#(#children),*]
+        }
+    }
+
+    /// Returns the elements which end up as direct children of the parent node, looking through
+    /// fragments.
+    pub fn elements(&self) -> Vec<&HtmlElement> {
+        self.0
+            .iter()
+            .flat_map(|child| match child {
+                HtmlTree::Element(element) => vec![&**element],
+                HtmlTree::List(list) => list.children().elements(),
+                _ => Vec::new(),
+            })
+            .collect()
+    }
+
     // Check if each child represents a single node.
     // This is the case when no expressions are used.
     fn only_single_node_children(&self) -> bool {
@@ -47,8 +47,10 @@
 //! Please refer to [https://github.com/yewstack/yew](https://github.com/yewstack/yew) for how to set this up.
 
 mod classes;
+mod css;
 mod derive_props;
 mod function_component;
+mod html_file;
 mod html_tree;
 mod props;
 mod stringify;
@@ -90,7 +92,7 @@ fn join_errors(mut it: impl Iterator<Item = syn::Error>) -> syn::Result<()> {
     })
 }
 
-#[proc_macro_derive(Properties, attributes(prop_or, prop_or_else, prop_or_default))]
+#[proc_macro_derive(Properties, attributes(prop, prop_or, prop_or_else, prop_or_default))]
 pub fn derive_props(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DerivePropsInput);
     TokenStream::from(input.into_token_stream())
@@ -108,18 +110,47 @@ pub fn html(input: TokenStream) -> TokenStream {
     TokenStream::from(root.into_token_stream())
 }
 
+#[proc_macro]
+pub fn html_file(input: TokenStream) -> TokenStream {
+    let file = parse_macro_input!(input as html_file::HtmlFile);
+    TokenStream::from(file.into_token_stream())
+}
+
 #[proc_macro]
 pub fn props(input: TokenStream) -> TokenStream {
     let props = parse_macro_input!(input as props::PropsMacroInput);
     TokenStream::from(props.into_token_stream())
 }
 
+#[doc(hidden)]
+#[proc_macro]
+pub fn __rest_props_methods(_input: TokenStream) -> TokenStream {
+    TokenStream::from(props::rest_props_methods())
+}
+
+#[doc(hidden)]
+#[proc_macro]
+pub fn __rest_fields(_input: TokenStream) -> TokenStream {
+    TokenStream::from(props::rest_fields())
+}
+
 #[proc_macro]
 pub fn classes(input: TokenStream) -> TokenStream {
     let classes = parse_macro_input!(input as classes::Classes);
     TokenStream::from(classes.into_token_stream())
 }
 
+#[proc_macro]
+pub fn css(input: TokenStream) -> TokenStream {
+    let css = parse_macro_input!(input as css::Css);
+    TokenStream::from(css.into_token_stream())
+}
+
+#[proc_macro]
+pub fn style(input: TokenStream) -> TokenStream {
+    css(input)
+}
+
 #[proc_macro_attribute]
 pub fn function_component(
     attr: proc_macro::TokenStream,
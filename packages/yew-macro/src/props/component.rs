@@ -1,5 +1,6 @@
-use super::{Prop, Props, SpecialProps, CHILDREN_LABEL};
-use proc_macro2::{Ident, TokenStream};
+use super::rest::{is_rest_candidate, is_rest_only};
+use super::{Prop, Props, SpecialProps, BOOLEAN_SET, CHILDREN_LABEL};
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::{quote, quote_spanned, ToTokens};
 use std::convert::TryFrom;
 use syn::{
@@ -49,30 +50,47 @@ impl ComponentProps {
         self.props.get_by_label(CHILDREN_LABEL)
     }
 
-    fn prop_validation_tokens(&self, props_ty: impl ToTokens, has_children: bool) -> TokenStream {
+    /// Returns an access of the field for every prop, which fails if the properties don't accept it.
+    fn field_checks(&self, props_ty: impl ToTokens, has_children: bool) -> Vec<TokenStream> {
         let check_children = if has_children {
-            Some(quote_spanned! {props_ty.span()=> __yew_props.children; })
+            Some(quote_spanned! {props_ty.span()=> __yew_props.children })
         } else {
             None
         };
 
-        let check_props: TokenStream = self
-            .props
-            .iter()
-            .map(|Prop { label, .. }| quote_spanned! ( label.span()=> __yew_props.#label; ))
-            .chain(self.base_expr.iter().map(|expr| {
-                quote_spanned! {props_ty.span()=>
-                    let _: #props_ty = #expr;
+        // Attributes and listeners might be collected by a `#[prop(rest)]` field instead, so
+        // they're checked against the fields which the properties accept. If a base expression is
+        // given, the ones which can be declared are set as fields and checked as such.
+        let check_props = self.props.iter().map(|Prop { label, .. }| {
+            let name = label.to_string();
+            if is_rest_only(&name) {
+                check_rest_only(&props_ty, label, &name)
+            } else if is_rest_candidate(&name) && self.base_expr.is_none() {
+                quote_spanned! {label.span()=>
+                    (&::yew::html::__props_probe::<#props_ty>()).__yew_fields().#label
                 }
-            }))
-            .collect();
+            } else {
+                quote_spanned! {label.span()=> __yew_props.#label }
+            }
+        });
+
+        check_children.into_iter().chain(check_props).collect()
+    }
+
+    fn prop_validation_tokens(&self, props_ty: impl ToTokens, has_children: bool) -> TokenStream {
+        let check_props = self.field_checks(&props_ty, has_children);
+        let check_base = self.base_expr.iter().map(|expr| {
+            quote_spanned! {props_ty.span()=>
+                let _: #props_ty = #expr;
+            }
+        });
 
         quote_spanned! {props_ty.span()=>
             #[allow(clippy::no_effect)]
             if false {
                 let _ = |__yew_props: #props_ty| {
-                    #check_children
-                    #check_props
+                    #(#check_props;)*
+                    #(#check_base)*
                 };
             }
         }
@@ -83,12 +101,19 @@ impl ComponentProps {
         props_ty: impl ToTokens,
         children_renderer: Option<CR>,
     ) -> TokenStream {
-        let validate_props = self.prop_validation_tokens(&props_ty, children_renderer.is_some());
+        let has_children = children_renderer.is_some();
         let build_props = match &self.base_expr {
             None => {
-                let set_props = self.props.iter().map(|Prop { label, value, .. }| {
-                    quote_spanned! {value.span()=>
-                        .#label(#value)
+                let check_props = self.field_checks(&props_ty, has_children);
+                let set_props = self.props.iter().map(|prop| {
+                    let Prop { label, value, .. } = prop;
+                    match rest_method(prop) {
+                        Some((method, name)) => quote_spanned! {value.span()=>
+                            .#method(#name, #value)
+                        },
+                        None => quote_spanned! {value.span()=>
+                            .#label(#value)
+                        },
                     }
                 });
 
@@ -98,8 +123,18 @@ impl ComponentProps {
                     }
                 });
 
+                // Brings the methods for undeclared attributes and listeners into scope. Unknown
+                // props are reported by the field checks instead of the builder.
                 quote_spanned! {props_ty.span()=>
-                    <#props_ty as ::yew::html::Properties>::builder()
+                    #[allow(unused_imports)]
+                    use ::yew::html::{
+                        PropsFieldsProbe as _, RestFieldsProbe as _, RestProps as _,
+                        RestPropsBuilder as _,
+                    };
+                    ::yew::html::__check_props(
+                        <#props_ty as ::yew::html::Properties>::builder(),
+                        |__yew_props: #props_ty| (#(#check_props,)*),
+                    )
                         #(#set_props)*
                         #set_children
                         .build()
@@ -108,9 +143,15 @@ impl ComponentProps {
             // Builder pattern is unnecessary in this case, since the base expression guarantees
             // all values are initialized
             Some(expr) => {
+                let validate_props = self.prop_validation_tokens(&props_ty, has_children);
                 let ident = Ident::new("__yew_props", props_ty.span());
-                let set_props = self.props.iter().map(|Prop { label, value, .. }| {
-                    if is_string_literal(value) {
+                let set_props = self.props.iter().map(|prop| {
+                    let Prop { label, value, .. } = prop;
+                    if let Some((method, name)) = rest_method(prop) {
+                        quote_spanned! {value.span()=>
+                            #ident = ::yew::html::RestPropsBuilder::#method(#ident, #name, #value);
+                        }
+                    } else if is_string_literal(value) {
                         // String literals should be implicitly converted into `String`
                         quote_spanned! {value.span()=>
                             #ident.#label = ::std::convert::Into::into(#value);
@@ -128,6 +169,7 @@ impl ComponentProps {
                 });
 
                 quote! {
+                    #validate_props
                     let mut #ident = #expr;
                     #(#set_props)*
                     #set_children
@@ -138,13 +180,83 @@ impl ComponentProps {
 
         quote! {
             {
-                #validate_props
                 #build_props
             }
         }
     }
 }
 
+/// Checks a prop which can only be collected by a rest field, like `aria-label` or `type`.
+///
+/// Which of the local traits provides the fields depends on whether the properties have a rest
+/// field. Without one the compiler reports the prop as a missing field of the properties, which
+/// lists the declared props, and the deprecation note explains why it isn't accepted.
+fn check_rest_only(props_ty: impl ToTokens, label: impl ToTokens, name: &str) -> TokenStream {
+    let span = label.span();
+    let field = rest_only_field(name, span);
+    let message = format!(
+        "`{}` isn't a prop of the component, which has no `#[prop(rest)]` field to collect it",
+        name
+    );
+    quote_spanned! {span=>
+        {
+            #[allow(dead_code)]
+            struct __YewRestFields {
+                #field: (),
+            }
+            trait __YewRestField {
+                fn rest_fields(&self) -> __YewRestFields {
+                    ::std::unreachable!()
+                }
+            }
+            impl<P: ::yew::html::HasRestField> __YewRestField for ::yew::html::PropsProbe<P> {}
+            #[allow(non_camel_case_types)]
+            trait unknown_prop<P> {
+                #[deprecated(note = #message)]
+                fn rest_fields(&self) -> P {
+                    ::std::unreachable!()
+                }
+            }
+            impl<P> unknown_prop<P> for &::yew::html::PropsProbe<P> {}
+
+            #[deny(deprecated)]
+            let __yew_fields = (&::yew::html::__props_probe::<#props_ty>()).rest_fields();
+            __yew_fields.#field
+        }
+    }
+}
+
+/// Returns the field which stands in for a prop that can't be the name of a field.
+fn rest_only_field(name: &str, span: Span) -> Ident {
+    let name: String = name
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect();
+    match name.as_str() {
+        "self" | "Self" | "super" | "crate" | "_" => Ident::new(&format!("{}_", name), span),
+        _ if name.starts_with(|c: char| c.is_ascii_digit()) => {
+            Ident::new(&format!("_{}", name), span)
+        }
+        _ if is_rest_only(&name) => Ident::new_raw(&name, span),
+        _ => Ident::new(&name, span),
+    }
+}
+
+/// Returns the `RestPropsBuilder` method for props which can only be collected by a rest field.
+fn rest_method(prop: &Prop) -> Option<(Ident, String)> {
+    let name = prop.label.to_string();
+    if !is_rest_only(&name) {
+        return None;
+    }
+
+    let method = if BOOLEAN_SET.contains(name.as_str()) {
+        "__yew_rest_boolean"
+    } else {
+        "__yew_rest_attribute"
+    };
+    Some((Ident::new(method, prop.label.span()), name))
+}
+
 fn is_string_literal(expr: &Expr) -> bool {
     matches!(
         expr,
@@ -189,16 +301,6 @@ impl TryFrom<Props> for ComponentProps {
 
 fn validate(props: Props) -> Result<Props, syn::Error> {
     props.check_no_duplicates()?;
-    props.check_all(|prop| {
-        if !prop.label.extended.is_empty() {
-            Err(syn::Error::new_spanned(
-                &prop.label,
-                "expected a valid Rust identifier",
-            ))
-        } else {
-            Ok(())
-        }
-    })?;
 
     Ok(props)
 }
@@ -2,7 +2,7 @@ use super::{Prop, Props, SpecialProps};
 use lazy_static::lazy_static;
 use std::collections::HashSet;
 use syn::parse::{Parse, ParseStream};
-use syn::{Expr, ExprTuple};
+use syn::{Expr, ExprLit, ExprRange, ExprTuple, Lit, Token};
 
 pub enum ClassesForm {
     Tuple(ExprTuple),
@@ -26,11 +26,16 @@ pub struct ElementProps {
     pub checked: Option<Prop>,
     pub node_ref: Option<Prop>,
     pub key: Option<Prop>,
+    /// Set by `lint="allow"`, which turns off the lints for the element.
+    pub allow_lints: bool,
+    /// The expression following `..`, whose attributes and listeners are spread onto the element.
+    pub spread: Option<Expr>,
 }
 
 impl Parse for ElementProps {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let mut props = input.parse::<Props>()?;
+        let spread = parse_spread(input)?;
 
         let listeners =
             props.drain_filter(|prop| LISTENER_SET.contains(prop.label.to_string().as_str()));
@@ -46,6 +51,7 @@ impl Parse for ElementProps {
             .map(|prop| ClassesForm::from_expr(prop.value));
         let value = props.pop("value");
         let checked = props.pop("checked");
+        let allow_lints = props.pop("lint").map(parse_lint).transpose()?.is_some();
 
         let SpecialProps { node_ref, key } = props.special;
 
@@ -58,12 +64,54 @@ impl Parse for ElementProps {
             value,
             node_ref,
             key,
+            allow_lints,
+            spread,
         })
     }
 }
 
+fn parse_spread(input: ParseStream) -> syn::Result<Option<Expr>> {
+    if input.is_empty() {
+        return Ok(None);
+    }
+
+    let dot2 = input.parse::<Token![..]>()?;
+    let expr = input.parse::<Expr>().map_err(|expr_error| {
+        let mut error =
+            syn::Error::new_spanned(dot2, "expected an expression to spread after `..`");
+        error.combine(expr_error);
+        error
+    })?;
+    if let Expr::Range(ExprRange { from: Some(_), .. }) = &expr {
+        return Err(syn::Error::new_spanned(
+            expr,
+            "only one expression can be spread onto an element",
+        ));
+    }
+    if input.is_empty() {
+        Ok(Some(expr))
+    } else {
+        Err(syn::Error::new_spanned(
+            expr,
+            "the spread expression must appear after all attributes and listeners",
+        ))
+    }
+}
+
+fn parse_lint(prop: Prop) -> syn::Result<()> {
+    match &prop.value {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(lit), ..
+        }) if lit.value() == "allow" => Ok(()),
+        value => Err(syn::Error::new_spanned(
+            value,
+            "the only supported value of `lint` is `\"allow\"`",
+        )),
+    }
+}
+
 lazy_static! {
-    static ref BOOLEAN_SET: HashSet<&'static str> = {
+    pub(crate) static ref BOOLEAN_SET: HashSet<&'static str> = {
         vec![
             "async",
             "autofocus",
@@ -89,7 +137,7 @@ lazy_static! {
 }
 
 lazy_static! {
-    static ref LISTENER_SET: HashSet<&'static str> = {
+    pub(crate) static ref LISTENER_SET: HashSet<&'static str> = {
         vec![
             // Living Standard
             // From: https://html.spec.whatwg.org/multipage/webappapis.html#globaleventhandlers
@@ -2,10 +2,12 @@ mod component;
 mod element;
 mod prop;
 mod prop_macro;
+mod rest;
 
 pub use component::*;
 pub use element::*;
 pub use prop::*;
 pub use prop_macro::PropsMacroInput;
+pub use rest::{rest_fields, rest_props_methods};
 
 const CHILDREN_LABEL: &str = "children";
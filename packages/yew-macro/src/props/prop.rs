@@ -252,12 +252,6 @@ impl SortedPropList {
         Self(drained)
     }
 
-    /// Run the given function for all props and aggregate the errors.
-    /// If there's at least one error, the result will be `Result::Err`.
-    pub fn check_all(&self, f: impl FnMut(&Prop) -> syn::Result<()>) -> syn::Result<()> {
-        crate::join_errors(self.0.iter().map(f).filter_map(Result::err))
-    }
-
     /// Return an error for all duplicate props.
     pub fn check_no_duplicates(&self) -> syn::Result<()> {
         crate::join_errors(self.iter_duplicates().map(|prop| {
@@ -0,0 +1,129 @@
+//! Attributes and listeners which are passed to a component with a `#[prop(rest)]` field.
+//!
+//! `html!` doesn't know which props a component declares. Every attribute and listener that a
+//! component could receive without declaring it is a method of the hidden `RestProps` trait,
+//! which is implemented for the builders of all derived properties. Declared props are inherent
+//! methods of the builder and take precedence over the trait methods, so only the undeclared ones
+//! end up in the rest field. `html!` checks these props against the hidden `RestFields` struct
+//! instead of the properties if they implement `HasRestField`, so components without a rest field
+//! still report them as unknown props.
+
+use super::{BOOLEAN_SET, LISTENER_SET};
+use lazy_static::lazy_static;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use std::collections::BTreeSet;
+use yew_validation::spec::{ELEMENTS, GLOBAL_ATTRIBUTES};
+
+/// Returns true if the label can't be the name of a declared prop.
+///
+/// These are the labels which aren't valid Rust identifiers, like `aria-label` or `type`.
+pub fn is_rest_only(label: &str) -> bool {
+    // syn accepts the keywords which were added in the 2018 edition as identifiers
+    matches!(label, "async" | "await" | "dyn" | "try") || syn::parse_str::<Ident>(label).is_err()
+}
+
+/// Returns true if the prop is routed into the rest field when the component doesn't declare it.
+pub fn is_rest_candidate(label: &str) -> bool {
+    is_rest_only(label)
+        || LISTENER_SET.contains(label)
+        || BOOLEAN_SET.contains(label)
+        || ATTRIBUTE_SET.contains(label)
+}
+
+lazy_static! {
+    /// Attributes which have a method of their own on the `RestProps` trait.
+    static ref ATTRIBUTE_SET: BTreeSet<&'static str> = {
+        GLOBAL_ATTRIBUTES
+            .iter()
+            .chain(ELEMENTS.iter().flat_map(|element| element.attributes))
+            .copied()
+            .filter(|name| {
+                !is_rest_only(name) && !LISTENER_SET.contains(name) && !BOOLEAN_SET.contains(name)
+            })
+            .collect()
+    };
+}
+
+/// Expands to the `RestFields` struct, which has a field for every attribute and listener that has
+/// a method on the `RestProps` trait.
+pub fn rest_fields() -> TokenStream {
+    let mut names: Vec<_> = LISTENER_SET
+        .iter()
+        .chain(BOOLEAN_SET.iter())
+        .chain(ATTRIBUTE_SET.iter())
+        .copied()
+        .filter(|name| !is_rest_only(name))
+        .collect();
+    names.sort_unstable();
+    names.dedup();
+    let fields = names
+        .into_iter()
+        .map(|name| Ident::new(name, Span::call_site()));
+
+    quote! {
+        #[doc(hidden)]
+        #[derive(Debug)]
+        pub struct RestFields {
+            #(pub #fields: (),)*
+        }
+    }
+}
+
+/// Expands to the methods of the `RestProps` trait.
+pub fn rest_props_methods() -> TokenStream {
+    let mut listeners: Vec<_> = LISTENER_SET.iter().copied().collect();
+    listeners.sort_unstable();
+    let listeners = listeners.into_iter().map(|name| {
+        let ident = Ident::new(name, Span::call_site());
+        quote! {
+            #[doc(hidden)]
+            fn #ident(self, callback: impl crate::html::IntoEventCallback<crate::html::#ident::Event>) -> Self {
+                self.__yew_rest_listener(crate::html::#ident::Wrapper::__macro_new(callback))
+            }
+        }
+    });
+
+    let mut booleans: Vec<_> = BOOLEAN_SET.iter().copied().collect();
+    booleans.sort_unstable();
+    let booleans = booleans
+        .into_iter()
+        .filter(|name| !is_rest_only(name))
+        .map(|name| {
+            let ident = Ident::new(name, Span::call_site());
+            quote! {
+                #[doc(hidden)]
+                fn #ident(self, value: bool) -> Self {
+                    self.__yew_rest_boolean(#name, value)
+                }
+            }
+        });
+
+    let attributes = ATTRIBUTE_SET.iter().map(|&name| {
+        let ident = Ident::new(name, Span::call_site());
+        if name == "class" {
+            quote! {
+                #[doc(hidden)]
+                fn class(self, value: impl ::std::convert::Into<crate::html::Classes>) -> Self {
+                    self.__yew_rest_attribute("class", value.into())
+                }
+            }
+        } else {
+            quote! {
+                #[doc(hidden)]
+                fn #ident(
+                    self,
+                    value: impl crate::html::IntoPropValue<::std::option::Option<crate::virtual_dom::AttrValue>>,
+                ) -> Self {
+                    self.__yew_rest_attribute(#name, value)
+                }
+            }
+        }
+    });
+
+    quote! {
+        #(#listeners)*
+        #(#booleans)*
+        #(#attributes)*
+    }
+}
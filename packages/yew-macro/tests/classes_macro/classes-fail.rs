@@ -16,6 +16,11 @@ fn compile_pass() {
     classes!("one", 42);
 
     classes!("one", "two three", "four");
+
+    classes!("one" => 1);
+    classes!({ "one": "yes" });
+    classes!({ "one two": true });
+    classes!({ "one": true, two: true });
 }
 
 fn main() {}
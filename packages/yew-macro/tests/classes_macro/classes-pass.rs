@@ -24,6 +24,19 @@ fn compile_pass() {
         use ::std::borrow::ToOwned;
         ::yew::classes!("one".to_owned(), "two", ::std::vec!["three"]);
     }
+
+    // conditional classes
+    let active = true;
+    ::yew::classes!("one", "two" => active, ::std::vec!["three"] => !active);
+    // map of conditional classes
+    ::yew::classes!({ "one": active, "two": !active, });
+    ::yew::classes!("one", { "two": active }, "three" => active);
+
+    // duplicate literals
+    ::yew::classes!("one", "one", "two" => active);
+
+    // blocks are still expressions
+    ::yew::classes!({ "one" }, { let two = "two"; two });
 }
 
 fn main() {}
@@ -0,0 +1,18 @@
+fn compile_fail() {
+    yew::css!();
+    yew::css!(color: red);
+    yew::css!("color: red;", "margin: 0;");
+
+    yew::css!("color red;");
+    yew::css!("a { color: red;");
+    yew::css!("color: red; }");
+    yew::css!(
+        "
+        color: red;
+        { margin: 0; }
+        "
+    );
+    yew::css!("@media (max-width: 600px)");
+}
+
+fn main() {}
@@ -0,0 +1,35 @@
+#![no_implicit_prelude]
+
+fn compile_pass() {
+    // declarations
+    ::yew::css!("color: red; margin: 0 auto;");
+    // raw strings, nesting, pseudo-classes and media queries
+    let style: ::yew::style::Style = ::yew::css!(
+        r#"
+        padding: 1em;
+        /* descendants */
+        h2, p { margin: 0; }
+        &:hover, .dark & { color: white; }
+        ::after { content: "}"; }
+        @media (max-width: 600px) {
+            padding: 0;
+            h2 { font-size: 1em; }
+        }
+        @keyframes fade { from { opacity: 0; } }
+        "#
+    );
+    // empty
+    ::yew::css!("");
+    // alias
+    ::yew::style!("display: flex;");
+
+    // usable as a class
+    ::yew::classes!("card", style);
+    ::yew::html! { <div class={style} /> };
+
+    // usable in constants
+    const STYLE: ::yew::style::Style = ::yew::css!("color: red;");
+    ::yew::classes!(STYLE);
+}
+
+fn main() {}
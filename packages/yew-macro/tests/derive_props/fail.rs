@@ -117,4 +117,26 @@ mod t10 {
     }
 }
 
+mod t11 {
+    use super::*;
+    #[derive(Clone, Properties, PartialEq)]
+    pub struct Props {
+        #[prop(rest)]
+        rest: ::yew::virtual_dom::Spread,
+        // ERROR: only one field can collect the rest
+        #[prop(rest)]
+        other: ::yew::virtual_dom::Spread,
+    }
+}
+
+mod t12 {
+    use super::*;
+    #[derive(Clone, Properties, PartialEq)]
+    pub struct Props {
+        // ERROR: `rest` is the only argument of `prop`
+        #[prop(other)]
+        other: ::yew::virtual_dom::Spread,
+    }
+}
+
 fn main() {}
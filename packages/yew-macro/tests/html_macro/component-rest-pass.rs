@@ -0,0 +1,116 @@
+#![no_implicit_prelude]
+
+#[derive(
+    ::std::clone::Clone, ::yew::Properties, ::std::default::Default, ::std::cmp::PartialEq,
+)]
+pub struct ButtonProperties {
+    pub label: ::std::string::String,
+    #[prop_or_default]
+    pub id: ::std::option::Option<::std::string::String>,
+    #[prop_or_default]
+    pub onclick: ::yew::Callback<()>,
+    #[prop(rest)]
+    pub rest: ::yew::virtual_dom::Spread,
+}
+
+pub struct Button;
+impl ::yew::Component for Button {
+    type Message = ();
+    type Properties = ButtonProperties;
+
+    fn create(_ctx: &::yew::Context<Self>) -> Self {
+        ::std::unimplemented!()
+    }
+    fn view(&self, ctx: &::yew::Context<Self>) -> ::yew::Html {
+        let onclick = ::std::clone::Clone::clone(&ctx.props().onclick);
+        ::yew::html! {
+            <button
+                id={::std::clone::Clone::clone(&ctx.props().id)}
+                onclick={move |_| onclick.emit(())}
+                ..::std::clone::Clone::clone(&ctx.props().rest)
+            >
+                { &ctx.props().label }
+            </button>
+        }
+    }
+}
+
+// Properties which don't derive `Properties` can still declare attribute-like props
+#[derive(::std::clone::Clone, ::std::cmp::PartialEq)]
+pub struct BadgeProperties {
+    pub class: ::yew::Classes,
+}
+
+pub struct BadgeBuilder {
+    class: ::yew::Classes,
+}
+
+impl BadgeBuilder {
+    pub fn class(self, class: impl ::std::convert::Into<::yew::Classes>) -> Self {
+        Self {
+            class: ::std::convert::Into::into(class),
+        }
+    }
+
+    pub fn build(self) -> BadgeProperties {
+        BadgeProperties { class: self.class }
+    }
+}
+
+impl ::yew::Properties for BadgeProperties {
+    type Builder = BadgeBuilder;
+
+    fn builder() -> Self::Builder {
+        BadgeBuilder {
+            class: ::std::default::Default::default(),
+        }
+    }
+}
+
+pub struct Badge;
+impl ::yew::Component for Badge {
+    type Message = ();
+    type Properties = BadgeProperties;
+
+    fn create(_ctx: &::yew::Context<Self>) -> Self {
+        ::std::unimplemented!()
+    }
+    fn view(&self, ctx: &::yew::Context<Self>) -> ::yew::Html {
+        ::yew::html! { <span class={::std::clone::Clone::clone(&ctx.props().class)} /> }
+    }
+}
+
+fn main() {
+    // `id` and `onclick` are declared, everything else is collected by `rest`
+    ::yew::html! {
+        <Button
+            label="Close"
+            id="close"
+            onclick={<::yew::Callback<()> as ::std::convert::From<_>>::from(|()| {})}
+            class={::yew::classes!("btn", "btn-close")}
+            title="Close the dialog"
+            aria-label="Close"
+            data-dismiss="modal"
+            type="button"
+            disabled=true
+            hidden={false}
+            onkeydown={|_: ::yew::events::KeyboardEvent| {}}
+            onmouseover={::std::option::Option::<::yew::Callback<::yew::events::MouseEvent>>::None}
+        />
+    };
+
+    let props = <ButtonProperties as ::yew::Properties>::builder()
+        .label("Close")
+        .build();
+    ::yew::html! { <Button aria-label="Close" type="button" ..props /> };
+
+    ::yew::html! { <Badge class="badge" /> };
+
+    let _ = ::yew::props! {
+        ButtonProperties {
+            label: "Close",
+            tabindex: "0",
+            onfocus: |_: ::yew::events::FocusEvent| {},
+        }
+    };
+}
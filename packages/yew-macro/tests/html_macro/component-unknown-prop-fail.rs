@@ -0,0 +1,42 @@
+use yew::prelude::*;
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct CardProperties {
+    pub title: String,
+    #[prop_or_default]
+    pub subtitle: String,
+    #[prop_or_default]
+    pub elevation: u32,
+}
+
+pub struct Card;
+impl Component for Card {
+    type Message = ();
+    type Properties = CardProperties;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        unimplemented!()
+    }
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        unimplemented!()
+    }
+}
+
+fn main() {
+    html! { <Card titel="Title" /> };
+    html! { <Card title="Title" subtitel="Subtitle" /> };
+    html! { <Card title="Title" elevaton=2 /> };
+    html! { <Card title="Title" shadow=2 /> };
+    html! { <Card title="Title" class="card" /> };
+    html! { <Card title="Title" onclick={Callback::from(|_| ())} /> };
+    html! { <Card title="Title" clas="card" /> };
+    html! { <Card title="Title" sub-title="Subtitle" /> };
+    html! { <Card title="Title" aria-lable="Card" /> };
+
+    let props = CardProperties {
+        title: "Title".to_owned(),
+        subtitle: String::new(),
+        elevation: 0,
+    };
+    html! { <Card subtitel="Subtitle" ..props /> };
+}
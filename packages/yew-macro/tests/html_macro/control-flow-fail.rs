@@ -0,0 +1,13 @@
+use yew::prelude::*;
+
+fn main() {
+    let items = vec![1, 2, 3];
+
+    html! { if { <span /> } };
+    html! { if true };
+    html! { if true { <span /> } else };
+    html! { match 1 };
+    html! { match 1 { 1 => "one", _ => <span /> } };
+    html! { match 1 { 1 => <span /> _ => <span /> } };
+    html! { for item in items.iter() };
+}
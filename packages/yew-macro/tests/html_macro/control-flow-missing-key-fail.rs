@@ -0,0 +1,15 @@
+#![deny(deprecated)]
+
+use yew::prelude::*;
+
+fn main() {
+    let items = vec![1, 2, 3];
+
+    let _ = html! {
+        <ul>
+            for item in items.iter() {
+                <li>{ item }</li>
+            }
+        </ul>
+    };
+}
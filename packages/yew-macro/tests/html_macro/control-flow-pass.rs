@@ -0,0 +1,43 @@
+#![no_implicit_prelude]
+
+fn main() {
+    let flag = true;
+    let maybe: ::std::option::Option<u32> = ::std::option::Option::Some(1);
+    let items = ::std::vec![1, 2, 3];
+
+    ::yew::html! { if flag { <span /> } };
+    ::yew::html! { if flag { <span /> } else { <div /> } };
+    ::yew::html! {
+        if !flag {
+            <span />
+        } else if let ::std::option::Option::Some(value) = maybe {
+            <span>{ value }</span>
+            <span>{ "and more" }</span>
+        } else {}
+    };
+    ::yew::html! {
+        <div>
+            if flag { { "yes" } } else { { "no" } }
+            match maybe {
+                ::std::option::Option::Some(0) | ::std::option::Option::None => <span />,
+                ::std::option::Option::Some(value) if value > 10 => { <b>{ value }</b> }
+                ::std::option::Option::Some(value) => <i>{ value }</i>,
+            }
+        </div>
+    };
+    ::yew::html! {
+        <ul>
+            for item in items.iter() {
+                <li key={*item}>{ item }</li>
+            }
+        </ul>
+    };
+    ::yew::html! {
+        for (index, item) in ::std::iter::Iterator::enumerate(items.iter()) {
+            <span key={index}>{ item }</span>
+            if *item > 1 { <hr key={::std::format!("hr-{}", index)} /> }
+        }
+    };
+    // Iterators are still rendered with `for iter`
+    ::yew::html! { for ::std::iter::Iterator::map(items.iter(), |item| ::yew::html! { <span>{ item }</span> }) };
+}
@@ -0,0 +1,31 @@
+#![deny(deprecated)]
+
+use yew::prelude::*;
+
+fn main() {
+    let onclick = Callback::from(|_: MouseEvent| ());
+    let onkeydown = Callback::from(|_: KeyboardEvent| ());
+
+    // missing alt text
+    let _ = html! { <img src="cat.png" /> };
+    let _ = html! { <area href="/" /> };
+    let _ = html! { <input type="image" src="submit.png" /> };
+
+    // invalid aria
+    let _ = html! { <div aria-lable="label" /> };
+    let _ = html! { <div role="buton" /> };
+
+    // clickable elements which can't be used with a keyboard
+    let _ = html! { <div onclick={onclick.clone()} /> };
+    let _ = html! { <span role="button" onclick={onclick.clone()} /> };
+
+    // these are fine
+    let _ = html! { <img src="cat.png" alt="" /> };
+    let _ = html! { <area /> };
+    let _ = html! { <input type="text" /> };
+    let _ = html! { <div aria-label="label" role="switch checkbox" /> };
+    let _ = html! { <div role="button" onclick={onclick.clone()} onkeydown={onkeydown.clone()} /> };
+    let _ = html! { <button onclick={onclick.clone()} /> };
+    let _ = html! { <my-element onclick={onclick.clone()} /> };
+    let _ = html! { <div onclick={onclick.clone()} lint="allow" /> };
+}
@@ -90,6 +90,10 @@ fn compile_fail() {
     html! { <input type=() /> };
     html! { <input value=() /> };
     html! { <input string=NotToString /> };
+
+    // unsupported lint levels
+    html! { <div lint="deny" /> };
+    html! { <div lint={true} /> };
 }
 
 fn main() {}
@@ -0,0 +1,13 @@
+#![deny(deprecated)]
+
+use yew::prelude::*;
+
+fn main() {
+    // compiles, but is linted
+    let _ = html! { <img class="avatar hidden" /> };
+    let _ = html! { <track kind="subtitles" mixed="works" /> };
+
+    // `lint="allow"` turns the lints off
+    let _ = html! { <img class="avatar hidden" lint="allow" /> };
+    let _ = html! { <track kind="subtitles" mixed="works" lint="allow" /> };
+}
@@ -0,0 +1,31 @@
+#![deny(deprecated)]
+
+use yew::prelude::*;
+
+fn main() {
+    // invalid children
+    let _ = html! { <p><div /></p> };
+    let _ = html! { <ul><span /></ul> };
+    let _ = html! { <ul><><li /><span /></></ul> };
+    let _ = html! { <a><a /></a> };
+    let _ = html! { <button><input /></button> };
+    let _ = html! { <form><form /></form> };
+    let _ = html! { <option><span /></option> };
+
+    // invalid attributes
+    let _ = html! { <input href="/" /> };
+    let _ = html! { <span checked=true /> };
+    let _ = html! { <a herf="/" /> };
+    let _ = html! { <input palceholder="Name" /> };
+
+    // these are fine
+    let _ = html! { <ul><li /><template /></ul> };
+    let _ = html! { <p><my-element /></p> };
+    let _ = html! { <ruby>{ "漢" }<rp>{ "(" }</rp><rt>{ "kan" }</rt><rp>{ ")" }</rp></ruby> };
+    let _ = html! { <my-element href="/"><div /></my-element> };
+    let _ = html! { <div data-id="1" aria-label="label" /> };
+    let _ = html! { <div value="1" /> };
+    let _ = html! { <a href="/" media="print" /> };
+    let _ = html! { <p><div lint="allow" /></p> };
+    let _ = html! { <input href="/" lint="allow" /> };
+}
@@ -0,0 +1,15 @@
+#![deny(deprecated)]
+
+use yew::prelude::*;
+use yew::virtual_dom::Spread;
+
+fn main() {
+    let onclick = Callback::from(|_: MouseEvent| ());
+
+    // The spread might provide the attributes and listeners the accessibility checks look for
+    let image = Spread::new().attribute("alt", "A cat");
+    let _ = html! { <img src="cat.png" ..image /> };
+
+    let button = Spread::new().attribute("role", "button");
+    let _ = html! { <div onclick={onclick.clone()} ..button /> };
+}
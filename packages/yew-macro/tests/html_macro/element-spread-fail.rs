@@ -0,0 +1,10 @@
+use yew::prelude::*;
+use yew::virtual_dom::Spread;
+
+fn main() {
+    let spread = Spread::new();
+
+    html! { <div .. /> };
+    html! { <div ..spread.clone() class="last" /> };
+    html! { <div ..spread.clone() ..spread /> };
+}
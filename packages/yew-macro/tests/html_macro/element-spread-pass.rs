@@ -0,0 +1,15 @@
+#![no_implicit_prelude]
+
+fn main() {
+    let spread = ::yew::virtual_dom::Spread::new().attribute("title", "spread");
+    let attributes = ::yew::virtual_dom::Attributes::Static(&[["id", "static"]]);
+
+    ::yew::html! {
+        <div class="parent" ..spread>
+            <button type="button" ..attributes>{ "Button" }</button>
+            <input ..::std::clone::Clone::clone(&spread) />
+            <textarea ..::std::clone::Clone::clone(&spread) />
+            <@{"span"} ..::std::clone::Clone::clone(&spread) />
+        </div>
+    };
+}
@@ -0,0 +1,11 @@
+use yew::prelude::*;
+
+fn compile_fail() {
+    html_file!();
+    html_file!(templates);
+    html_file!("templates/card.html" "templates/list.html");
+    // The tests are compiled in `target/tests/trybuild/yew-macro`
+    html_file!("../../../../packages/yew-macro/tests/html_macro/templates/invalid.html");
+}
+
+fn main() {}
@@ -28,3 +28,49 @@ fn dynamic_tags_catch_non_ascii() {
         <@{"❤"}/>
     };
 }
+
+#[test]
+fn control_flow_renders_branches() {
+    use yew::virtual_dom::VNode;
+
+    let render = |value: Option<u32>| {
+        html! {
+            match value {
+                Some(value) if value > 10 => <b>{ value }</b>,
+                Some(value) => { for i in 0..value { <i key={i} /> } }
+                None => <></>,
+            }
+        }
+    };
+
+    match render(Some(11)) {
+        VNode::VTag(tag) => assert_eq!(tag.tag(), "b"),
+        node => panic!("expected a tag, got {:?}", node),
+    }
+    match render(Some(3)) {
+        VNode::VList(list) => assert_eq!(list.len(), 3),
+        node => panic!("expected a list, got {:?}", node),
+    }
+
+    let empty = html! { if false { <span /> } };
+    match empty {
+        VNode::VList(list) => assert!(list.is_empty()),
+        node => panic!("expected an empty list, got {:?}", node),
+    }
+}
+
+#[test]
+fn html_file_renders_template() {
+    use yew::html_file;
+    use yew::virtual_dom::VNode;
+
+    let title = "Title";
+    let body = Some("Body");
+    match html_file!("tests/html_macro/templates/card.html") {
+        VNode::VTag(tag) => {
+            assert_eq!(tag.tag(), "div");
+            assert_eq!(tag.children().len(), 2);
+        }
+        node => panic!("expected a tag, got {:?}", node),
+    }
+}
@@ -0,0 +1,210 @@
+//! The roles and attributes defined by [WAI-ARIA 1.2](https://www.w3.org/TR/wai-aria-1.2/).
+
+/// Every non-abstract role.
+///
+/// See <https://www.w3.org/TR/wai-aria-1.2/#role_definitions>.
+pub const ROLES: &[&str] = &[
+    "alert",
+    "alertdialog",
+    "application",
+    "article",
+    "banner",
+    "blockquote",
+    "button",
+    "caption",
+    "cell",
+    "checkbox",
+    "code",
+    "columnheader",
+    "combobox",
+    "complementary",
+    "contentinfo",
+    "definition",
+    "deletion",
+    "dialog",
+    "directory",
+    "document",
+    "emphasis",
+    "feed",
+    "figure",
+    "form",
+    "generic",
+    "grid",
+    "gridcell",
+    "group",
+    "heading",
+    "img",
+    "insertion",
+    "link",
+    "list",
+    "listbox",
+    "listitem",
+    "log",
+    "main",
+    "marquee",
+    "math",
+    "menu",
+    "menubar",
+    "menuitem",
+    "menuitemcheckbox",
+    "menuitemradio",
+    "meter",
+    "navigation",
+    "none",
+    "note",
+    "option",
+    "paragraph",
+    "presentation",
+    "progressbar",
+    "radio",
+    "radiogroup",
+    "region",
+    "row",
+    "rowgroup",
+    "rowheader",
+    "scrollbar",
+    "search",
+    "searchbox",
+    "separator",
+    "slider",
+    "spinbutton",
+    "status",
+    "strong",
+    "subscript",
+    "superscript",
+    "switch",
+    "tab",
+    "table",
+    "tablist",
+    "tabpanel",
+    "term",
+    "textbox",
+    "time",
+    "timer",
+    "toolbar",
+    "tooltip",
+    "tree",
+    "treegrid",
+    "treeitem",
+];
+
+/// Every `aria-*` attribute, including the deprecated ones which browsers still support.
+///
+/// See <https://www.w3.org/TR/wai-aria-1.2/#state_prop_def>.
+pub const ATTRIBUTES: &[&str] = &[
+    "aria-activedescendant",
+    "aria-atomic",
+    "aria-autocomplete",
+    "aria-braillelabel",
+    "aria-brailleroledescription",
+    "aria-busy",
+    "aria-checked",
+    "aria-colcount",
+    "aria-colindex",
+    "aria-colindextext",
+    "aria-colspan",
+    "aria-controls",
+    "aria-current",
+    "aria-describedby",
+    "aria-description",
+    "aria-details",
+    "aria-disabled",
+    "aria-dropeffect",
+    "aria-errormessage",
+    "aria-expanded",
+    "aria-flowto",
+    "aria-grabbed",
+    "aria-haspopup",
+    "aria-hidden",
+    "aria-invalid",
+    "aria-keyshortcuts",
+    "aria-label",
+    "aria-labelledby",
+    "aria-level",
+    "aria-live",
+    "aria-modal",
+    "aria-multiline",
+    "aria-multiselectable",
+    "aria-orientation",
+    "aria-owns",
+    "aria-placeholder",
+    "aria-posinset",
+    "aria-pressed",
+    "aria-readonly",
+    "aria-relevant",
+    "aria-required",
+    "aria-roledescription",
+    "aria-rowcount",
+    "aria-rowindex",
+    "aria-rowindextext",
+    "aria-rowspan",
+    "aria-selected",
+    "aria-setsize",
+    "aria-sort",
+    "aria-valuemax",
+    "aria-valuemin",
+    "aria-valuenow",
+    "aria-valuetext",
+];
+
+/// Returns true if `role` is a valid value for the `role` attribute.
+///
+/// The attribute can contain a space separated list of fallback roles, all of which need to be
+/// valid.
+pub fn is_valid_role(role: &str) -> bool {
+    let mut roles = role.split_ascii_whitespace().peekable();
+    roles.peek().is_some() && roles.all(|role| ROLES.contains(&role))
+}
+
+/// Returns true if `attribute` is an `aria-*` attribute defined by the spec.
+pub fn is_valid_attribute(attribute: &str) -> bool {
+    ATTRIBUTES.contains(&attribute.to_ascii_lowercase().as_str())
+}
+
+/// Returns the role whose name is closest to `role`, if one is likely to be what was meant.
+pub fn suggest_role(role: &str) -> Option<&'static str> {
+    crate::closest_match(role, ROLES.iter().copied())
+}
+
+/// Returns the `aria-*` attribute whose name is closest to `attribute`, if one is likely to be
+/// what was meant.
+pub fn suggest_attribute(attribute: &str) -> Option<&'static str> {
+    crate::closest_match(attribute, ATTRIBUTES.iter().copied())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tables_are_sorted() {
+        assert!(ROLES.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(ATTRIBUTES.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn roles() {
+        assert!(is_valid_role("button"));
+        assert!(is_valid_role("switch checkbox"));
+        assert!(!is_valid_role(""));
+        assert!(!is_valid_role("buton"));
+        assert!(!is_valid_role("switch chekbox"));
+    }
+
+    #[test]
+    fn attributes() {
+        assert!(is_valid_attribute("aria-label"));
+        assert!(is_valid_attribute("ARIA-LABEL"));
+        assert!(!is_valid_attribute("aria-lable"));
+        assert!(!is_valid_attribute("aria-"));
+    }
+
+    #[test]
+    fn suggestions() {
+        assert_eq!(suggest_role("buton"), Some("button"));
+        assert_eq!(suggest_role("chekbox"), Some("checkbox"));
+        assert_eq!(suggest_role("widget"), None);
+        assert_eq!(suggest_attribute("aria-lable"), Some("aria-label"));
+        assert_eq!(suggest_attribute("aria-hiden"), Some("aria-hidden"));
+    }
+}
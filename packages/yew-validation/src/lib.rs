@@ -1,5 +1,11 @@
 //! Utility library for the Yew frontend web framework to handle validating strings relating
 //! to HTML/SVG/MathML tags.
+//!
+//! The [`spec`] module contains a database of the HTML elements and the children and attributes
+//! they accept, and the [`aria`] module contains the roles and attributes defined by WAI-ARIA.
+
+pub mod aria;
+pub mod spec;
 
 /// Returns true when the character provided is a "control" as defined
 /// in [the WhatWG spec](https://infra.spec.whatwg.org/#control)
@@ -120,7 +126,7 @@ fn is_valid_html_custom_element_name(tag: &str) -> bool {
         None => false,
         Some(first_char) => {
             // must begin with [a-z]
-            if !('a'..='z').contains(&first_char) {
+            if !first_char.is_ascii_lowercase() {
                 return false;
             }
 
@@ -169,6 +175,53 @@ pub fn is_valid_sgml_tag(tag: &str) -> bool {
     resembles_standard_html_element_name(tag) || is_valid_html_custom_element_name(tag)
 }
 
+/// Returns the number of single character insertions, deletions, substitutions and swaps of
+/// adjacent characters which turn `a` into `b`.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut before: Vec<usize> = Vec::new();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for i in 1..=a.len() {
+        let mut current = vec![i; b.len() + 1];
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            current[j] = (previous[j] + 1)
+                .min(current[j - 1] + 1)
+                .min(previous[j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                current[j] = current[j].min(before[j - 2] + 1);
+            }
+        }
+        before = std::mem::replace(&mut previous, current);
+    }
+    previous[b.len()]
+}
+
+/// Returns the candidate which is closest to `name`, if any of them is close enough to be a
+/// likely typo.
+///
+/// A candidate is close enough if it's at most a third of the length of `name` edits away.
+/// Ties go to the candidate which comes first.
+pub fn closest_match<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let name = name.to_ascii_lowercase();
+    let max_distance = (name.chars().count() / 3).max(1);
+    candidates
+        .into_iter()
+        .map(|candidate| {
+            (
+                edit_distance(&name, &candidate.to_ascii_lowercase()),
+                candidate,
+            )
+        })
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -229,4 +282,33 @@ mod tests {
         assert!(!is_valid_sgml_tag("f<bar"));
         assert!(!is_valid_sgml_tag("/>"));
     }
+
+    #[test]
+    fn edit_distances() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("class", "class"), 0);
+        assert_eq!(edit_distance("clas", "class"), 1);
+        assert_eq!(edit_distance("calss", "class"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "id"), 2);
+    }
+
+    #[test]
+    fn closest_matches() {
+        let candidates = ["class", "title", "tabindex"];
+        assert_eq!(
+            closest_match("clas", candidates.iter().copied()),
+            Some("class")
+        );
+        assert_eq!(
+            closest_match("titel", candidates.iter().copied()),
+            Some("title")
+        );
+        assert_eq!(
+            closest_match("TabIndx", candidates.iter().copied()),
+            Some("tabindex")
+        );
+        assert_eq!(closest_match("onclick", candidates.iter().copied()), None);
+        assert_eq!(closest_match("x", candidates.iter().copied()), None);
+    }
 }
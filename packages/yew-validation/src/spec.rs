@@ -0,0 +1,821 @@
+//! A small database of the HTML elements defined by
+//! [the WhatWG spec](https://html.spec.whatwg.org/multipage/indices.html#elements-3), with the
+//! children and attributes each of them accepts.
+//!
+//! Only the rules that can be checked by looking at an element and its direct children are
+//! covered. Elements which aren't in the database, like custom elements and SVG elements, are
+//! never reported.
+
+use std::fmt;
+
+/// The content model of an element, simplified to what can be checked statically.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Content {
+    /// Any element is allowed.
+    Flow,
+    /// Only [phrasing content](https://html.spec.whatwg.org/multipage/dom.html#phrasing-content)
+    /// is allowed.
+    Phrasing,
+    /// Phrasing content and the listed elements are allowed.
+    PhrasingAnd(&'static [&'static str]),
+    /// Only the listed elements are allowed, besides `<script>` and `<template>`.
+    Only(&'static [&'static str]),
+    /// Only text is allowed.
+    Text,
+    /// The element is a void element and can't have any children.
+    Void,
+}
+
+/// An element of the HTML spec.
+#[derive(Debug)]
+pub struct Element {
+    /// The tag name.
+    pub name: &'static str,
+    /// The elements it can contain.
+    pub content: Content,
+    /// Interactive content isn't allowed inside the element.
+    pub no_interactive: bool,
+    /// Elements that aren't allowed as children even though the content model would allow them.
+    pub forbidden: &'static [&'static str],
+    /// Attributes of the element, besides the global ones.
+    pub attributes: &'static [&'static str],
+}
+
+/// The reason an element can't be a child of another one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ContentViolation {
+    /// The parent is a void element.
+    Void,
+    /// The parent only accepts text.
+    TextOnly,
+    /// The parent only accepts phrasing content.
+    PhrasingOnly,
+    /// The parent only accepts the listed elements.
+    NotListed(&'static [&'static str]),
+    /// The parent doesn't accept interactive content.
+    Interactive,
+    /// The parent doesn't accept this element specifically.
+    Forbidden,
+}
+
+/// An element which can't be a child of another one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InvalidChild<'a> {
+    /// The tag name of the parent.
+    pub parent: &'a str,
+    /// The tag name of the child.
+    pub child: &'a str,
+    /// Why the child isn't allowed.
+    pub reason: ContentViolation,
+}
+
+impl fmt::Display for InvalidChild<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            parent,
+            child,
+            reason,
+        } = self;
+        write!(f, "`<{}>` is not allowed inside `<{}>`", child, parent)?;
+        match reason {
+            ContentViolation::Void => {
+                write!(
+                    f,
+                    ": `<{}>` is a void element and can't have children",
+                    parent
+                )
+            }
+            ContentViolation::TextOnly => write!(f, ": `<{}>` can only contain text", parent),
+            ContentViolation::PhrasingOnly => {
+                write!(f, ": `<{}>` can only contain phrasing content", parent)
+            }
+            ContentViolation::NotListed(allowed) => {
+                let allowed: Vec<String> =
+                    allowed.iter().map(|tag| format!("`<{}>`", tag)).collect();
+                write!(
+                    f,
+                    ": `<{}>` can only contain {}",
+                    parent,
+                    allowed.join(", ")
+                )
+            }
+            ContentViolation::Interactive => write!(
+                f,
+                ": interactive content can't be nested inside `<{}>`",
+                parent
+            ),
+            ContentViolation::Forbidden => Ok(()),
+        }
+    }
+}
+
+/// Attributes which are allowed on every element.
+///
+/// `data-*` and `aria-*` attributes are allowed as well.
+pub const GLOBAL_ATTRIBUTES: &[&str] = &[
+    "accesskey",
+    "autocapitalize",
+    "autofocus",
+    "class",
+    "contenteditable",
+    "dir",
+    "draggable",
+    "enterkeyhint",
+    "hidden",
+    "id",
+    "inert",
+    "inputmode",
+    "is",
+    "itemid",
+    "itemprop",
+    "itemref",
+    "itemscope",
+    "itemtype",
+    "lang",
+    "nonce",
+    "part",
+    "role",
+    "slot",
+    "spellcheck",
+    "style",
+    "tabindex",
+    "title",
+    "translate",
+];
+
+/// Elements which are [phrasing content](https://html.spec.whatwg.org/multipage/dom.html#phrasing-content).
+pub const PHRASING_CONTENT: &[&str] = &[
+    "a", "abbr", "area", "audio", "b", "bdi", "bdo", "br", "button", "canvas", "cite", "code",
+    "data", "datalist", "del", "dfn", "em", "embed", "i", "iframe", "img", "input", "ins", "kbd",
+    "label", "link", "map", "mark", "math", "meta", "meter", "noscript", "object", "output",
+    "picture", "progress", "q", "ruby", "s", "samp", "script", "select", "slot", "small", "span",
+    "strong", "sub", "sup", "svg", "template", "textarea", "time", "u", "var", "video", "wbr",
+];
+
+/// Elements which are [interactive content](https://html.spec.whatwg.org/multipage/dom.html#interactive-content).
+pub const INTERACTIVE_CONTENT: &[&str] = &[
+    "a", "button", "details", "embed", "iframe", "input", "label", "select", "textarea",
+];
+
+/// Attributes which aren't in the spec of an element but which browsers accept and which are
+/// common in existing markup, so they aren't flagged. `None` stands for every element.
+const TOLERATED_ATTRIBUTES: &[(Option<&str>, &str)] = &[
+    // Set as a plain attribute on elements that don't have a `value` property
+    (None, "value"),
+    // Obsolete, but still reflected by browsers
+    (Some("a"), "media"),
+];
+
+const fn element(name: &'static str, content: Content) -> Element {
+    Element {
+        name,
+        content,
+        no_interactive: false,
+        forbidden: &[],
+        attributes: &[],
+    }
+}
+
+const fn with_attributes(
+    name: &'static str,
+    content: Content,
+    attributes: &'static [&'static str],
+) -> Element {
+    Element {
+        name,
+        content,
+        no_interactive: false,
+        forbidden: &[],
+        attributes,
+    }
+}
+
+/// Every element in the database, sorted by name.
+pub const ELEMENTS: &[Element] = &[
+    Element {
+        name: "a",
+        // The content model is transparent, which can't be checked without the ancestors
+        content: Content::Flow,
+        no_interactive: true,
+        forbidden: &[],
+        attributes: &[
+            "download",
+            "href",
+            "hreflang",
+            "ping",
+            "referrerpolicy",
+            "rel",
+            "target",
+            "type",
+        ],
+    },
+    element("abbr", Content::Phrasing),
+    Element {
+        name: "address",
+        content: Content::Flow,
+        no_interactive: false,
+        forbidden: &[
+            "address", "article", "aside", "footer", "h1", "h2", "h3", "h4", "h5", "h6", "header",
+            "nav", "section",
+        ],
+        attributes: &[],
+    },
+    with_attributes(
+        "area",
+        Content::Void,
+        &[
+            "alt",
+            "coords",
+            "download",
+            "href",
+            "ping",
+            "referrerpolicy",
+            "rel",
+            "shape",
+            "target",
+        ],
+    ),
+    element("article", Content::Flow),
+    element("aside", Content::Flow),
+    with_attributes(
+        "audio",
+        Content::Flow,
+        &[
+            "autoplay",
+            "controls",
+            "crossorigin",
+            "loop",
+            "muted",
+            "preload",
+            "src",
+        ],
+    ),
+    element("b", Content::Phrasing),
+    with_attributes("base", Content::Void, &["href", "target"]),
+    element("bdi", Content::Phrasing),
+    element("bdo", Content::Phrasing),
+    with_attributes("blockquote", Content::Flow, &["cite"]),
+    element("body", Content::Flow),
+    element("br", Content::Void),
+    Element {
+        name: "button",
+        content: Content::Phrasing,
+        no_interactive: true,
+        forbidden: &[],
+        attributes: &[
+            "disabled",
+            "form",
+            "formaction",
+            "formenctype",
+            "formmethod",
+            "formnovalidate",
+            "formtarget",
+            "name",
+            "popovertarget",
+            "popovertargetaction",
+            "type",
+            "value",
+        ],
+    },
+    with_attributes("canvas", Content::Flow, &["height", "width"]),
+    Element {
+        name: "caption",
+        content: Content::Flow,
+        no_interactive: false,
+        forbidden: &["table"],
+        attributes: &[],
+    },
+    element("cite", Content::Phrasing),
+    element("code", Content::Phrasing),
+    with_attributes("col", Content::Void, &["span"]),
+    with_attributes("colgroup", Content::Only(&["col"]), &["span"]),
+    with_attributes("data", Content::Phrasing, &["value"]),
+    element("datalist", Content::Only(&["option"])),
+    element("dd", Content::Flow),
+    with_attributes("del", Content::Flow, &["cite", "datetime"]),
+    with_attributes("details", Content::Flow, &["name", "open"]),
+    Element {
+        name: "dfn",
+        content: Content::Phrasing,
+        no_interactive: false,
+        forbidden: &["dfn"],
+        attributes: &[],
+    },
+    with_attributes("dialog", Content::Flow, &["open"]),
+    element("div", Content::Flow),
+    element("dl", Content::Only(&["dd", "div", "dt"])),
+    Element {
+        name: "dt",
+        content: Content::Flow,
+        no_interactive: false,
+        forbidden: &[
+            "article", "aside", "footer", "h1", "h2", "h3", "h4", "h5", "h6", "header", "nav",
+            "section",
+        ],
+        attributes: &[],
+    },
+    element("em", Content::Phrasing),
+    with_attributes("embed", Content::Void, &["height", "src", "type", "width"]),
+    with_attributes("fieldset", Content::Flow, &["disabled", "form", "name"]),
+    element("figcaption", Content::Flow),
+    element("figure", Content::Flow),
+    Element {
+        name: "footer",
+        content: Content::Flow,
+        no_interactive: false,
+        forbidden: &["footer", "header", "main"],
+        attributes: &[],
+    },
+    Element {
+        name: "form",
+        content: Content::Flow,
+        no_interactive: false,
+        forbidden: &["form"],
+        attributes: &[
+            "accept-charset",
+            "action",
+            "autocomplete",
+            "enctype",
+            "method",
+            "name",
+            "novalidate",
+            "rel",
+            "target",
+        ],
+    },
+    element("h1", Content::Phrasing),
+    element("h2", Content::Phrasing),
+    element("h3", Content::Phrasing),
+    element("h4", Content::Phrasing),
+    element("h5", Content::Phrasing),
+    element("h6", Content::Phrasing),
+    element(
+        "head",
+        Content::Only(&[
+            "base", "link", "meta", "noscript", "script", "style", "template", "title",
+        ]),
+    ),
+    Element {
+        name: "header",
+        content: Content::Flow,
+        no_interactive: false,
+        forbidden: &["footer", "header", "main"],
+        attributes: &[],
+    },
+    element(
+        "hgroup",
+        Content::Only(&["h1", "h2", "h3", "h4", "h5", "h6", "p"]),
+    ),
+    element("hr", Content::Void),
+    with_attributes("html", Content::Only(&["body", "head"]), &["xmlns"]),
+    element("i", Content::Phrasing),
+    with_attributes(
+        "iframe",
+        Content::Text,
+        &[
+            "allow",
+            "allowfullscreen",
+            "height",
+            "loading",
+            "name",
+            "referrerpolicy",
+            "sandbox",
+            "src",
+            "srcdoc",
+            "width",
+        ],
+    ),
+    with_attributes(
+        "img",
+        Content::Void,
+        &[
+            "alt",
+            "crossorigin",
+            "decoding",
+            "fetchpriority",
+            "height",
+            "ismap",
+            "loading",
+            "referrerpolicy",
+            "sizes",
+            "src",
+            "srcset",
+            "usemap",
+            "width",
+        ],
+    ),
+    with_attributes(
+        "input",
+        Content::Void,
+        &[
+            "accept",
+            "alt",
+            "autocomplete",
+            "checked",
+            "dirname",
+            "disabled",
+            "form",
+            "formaction",
+            "formenctype",
+            "formmethod",
+            "formnovalidate",
+            "formtarget",
+            "height",
+            "list",
+            "max",
+            "maxlength",
+            "min",
+            "minlength",
+            "multiple",
+            "name",
+            "pattern",
+            "placeholder",
+            "popovertarget",
+            "popovertargetaction",
+            "readonly",
+            "required",
+            "size",
+            "src",
+            "step",
+            "type",
+            "value",
+            "width",
+        ],
+    ),
+    with_attributes("ins", Content::Flow, &["cite", "datetime"]),
+    element("kbd", Content::Phrasing),
+    Element {
+        name: "label",
+        content: Content::Phrasing,
+        no_interactive: false,
+        forbidden: &["label"],
+        attributes: &["for"],
+    },
+    element("legend", Content::Flow),
+    with_attributes("li", Content::Flow, &["value"]),
+    with_attributes(
+        "link",
+        Content::Void,
+        &[
+            "as",
+            "blocking",
+            "crossorigin",
+            "disabled",
+            "fetchpriority",
+            "href",
+            "hreflang",
+            "imagesizes",
+            "imagesrcset",
+            "integrity",
+            "media",
+            "referrerpolicy",
+            "rel",
+            "sizes",
+            "type",
+        ],
+    ),
+    element("main", Content::Flow),
+    with_attributes("map", Content::Flow, &["name"]),
+    element("mark", Content::Phrasing),
+    element("menu", Content::Only(&["li"])),
+    with_attributes(
+        "meta",
+        Content::Void,
+        &["charset", "content", "http-equiv", "media", "name"],
+    ),
+    Element {
+        name: "meter",
+        content: Content::Phrasing,
+        no_interactive: false,
+        forbidden: &["meter"],
+        attributes: &["high", "low", "max", "min", "optimum", "value"],
+    },
+    element("nav", Content::Flow),
+    element("noscript", Content::Flow),
+    with_attributes(
+        "object",
+        Content::Flow,
+        &["data", "form", "height", "name", "type", "width"],
+    ),
+    with_attributes("ol", Content::Only(&["li"]), &["reversed", "start", "type"]),
+    with_attributes(
+        "optgroup",
+        Content::Only(&["option"]),
+        &["disabled", "label"],
+    ),
+    with_attributes(
+        "option",
+        Content::Text,
+        &["disabled", "label", "selected", "value"],
+    ),
+    with_attributes("output", Content::Phrasing, &["for", "form", "name"]),
+    element("p", Content::Phrasing),
+    element("picture", Content::Only(&["img", "source"])),
+    element("pre", Content::Phrasing),
+    Element {
+        name: "progress",
+        content: Content::Phrasing,
+        no_interactive: false,
+        forbidden: &["progress"],
+        attributes: &["max", "value"],
+    },
+    with_attributes("q", Content::Phrasing, &["cite"]),
+    element("rp", Content::Text),
+    element("rt", Content::Phrasing),
+    element("ruby", Content::PhrasingAnd(&["rp", "rt"])),
+    element("s", Content::Phrasing),
+    element("samp", Content::Phrasing),
+    with_attributes(
+        "script",
+        Content::Text,
+        &[
+            "async",
+            "blocking",
+            "crossorigin",
+            "defer",
+            "fetchpriority",
+            "integrity",
+            "nomodule",
+            "referrerpolicy",
+            "src",
+            "type",
+        ],
+    ),
+    element("search", Content::Flow),
+    element("section", Content::Flow),
+    with_attributes(
+        "select",
+        Content::Only(&["hr", "optgroup", "option"]),
+        &[
+            "autocomplete",
+            "disabled",
+            "form",
+            "multiple",
+            "name",
+            "required",
+            "size",
+        ],
+    ),
+    with_attributes("slot", Content::Flow, &["name"]),
+    element("small", Content::Phrasing),
+    with_attributes(
+        "source",
+        Content::Void,
+        &["height", "media", "sizes", "src", "srcset", "type", "width"],
+    ),
+    element("span", Content::Phrasing),
+    element("strong", Content::Phrasing),
+    with_attributes("style", Content::Text, &["blocking", "media"]),
+    element("sub", Content::Phrasing),
+    element("summary", Content::Flow),
+    element("sup", Content::Phrasing),
+    element(
+        "table",
+        Content::Only(&["caption", "colgroup", "tbody", "tfoot", "thead", "tr"]),
+    ),
+    element("tbody", Content::Only(&["tr"])),
+    with_attributes("td", Content::Flow, &["colspan", "headers", "rowspan"]),
+    element("template", Content::Flow),
+    with_attributes(
+        "textarea",
+        Content::Text,
+        &[
+            "autocomplete",
+            "cols",
+            "dirname",
+            "disabled",
+            "form",
+            "maxlength",
+            "minlength",
+            "name",
+            "placeholder",
+            "readonly",
+            "required",
+            "rows",
+            "wrap",
+        ],
+    ),
+    element("tfoot", Content::Only(&["tr"])),
+    Element {
+        name: "th",
+        content: Content::Flow,
+        no_interactive: false,
+        forbidden: &[
+            "article", "aside", "footer", "h1", "h2", "h3", "h4", "h5", "h6", "header", "nav",
+            "section",
+        ],
+        attributes: &["abbr", "colspan", "headers", "rowspan", "scope"],
+    },
+    element("thead", Content::Only(&["tr"])),
+    with_attributes("time", Content::Phrasing, &["datetime"]),
+    element("title", Content::Text),
+    element("tr", Content::Only(&["td", "th"])),
+    with_attributes(
+        "track",
+        Content::Void,
+        &["default", "kind", "label", "src", "srclang"],
+    ),
+    element("u", Content::Phrasing),
+    element("ul", Content::Only(&["li"])),
+    element("var", Content::Phrasing),
+    with_attributes(
+        "video",
+        Content::Flow,
+        &[
+            "autoplay",
+            "controls",
+            "crossorigin",
+            "height",
+            "loop",
+            "muted",
+            "playsinline",
+            "poster",
+            "preload",
+            "src",
+            "width",
+        ],
+    ),
+    element("wbr", Content::Void),
+];
+
+/// Looks up an element by its tag name. The lookup is case insensitive.
+pub fn element_spec(tag: &str) -> Option<&'static Element> {
+    let tag = tag.to_ascii_lowercase();
+    ELEMENTS
+        .binary_search_by(|element| element.name.cmp(tag.as_str()))
+        .ok()
+        .map(|index| &ELEMENTS[index])
+}
+
+/// Returns true if `child` is [phrasing content](https://html.spec.whatwg.org/multipage/dom.html#phrasing-content).
+///
+/// Elements which aren't in the database are assumed to be.
+pub fn is_phrasing_content(child: &str) -> bool {
+    element_spec(child).is_none() || PHRASING_CONTENT.contains(&child.to_ascii_lowercase().as_str())
+}
+
+/// Returns true if `child` is [interactive content](https://html.spec.whatwg.org/multipage/dom.html#interactive-content).
+pub fn is_interactive_content(child: &str) -> bool {
+    INTERACTIVE_CONTENT.contains(&child.to_ascii_lowercase().as_str())
+}
+
+/// Checks whether the element `child` can be a direct child of `parent`.
+///
+/// Returns `Ok(())` if either element isn't in the database.
+pub fn check_child<'a>(parent: &'a str, child: &'a str) -> Result<(), InvalidChild<'a>> {
+    let (spec, child_spec) = match (element_spec(parent), element_spec(child)) {
+        (Some(spec), Some(child_spec)) => (spec, child_spec),
+        _ => return Ok(()),
+    };
+    let invalid = |reason| {
+        Err(InvalidChild {
+            parent,
+            child,
+            reason,
+        })
+    };
+
+    match spec.content {
+        Content::Void => return invalid(ContentViolation::Void),
+        Content::Text => return invalid(ContentViolation::TextOnly),
+        Content::Phrasing if !is_phrasing_content(child_spec.name) => {
+            return invalid(ContentViolation::PhrasingOnly)
+        }
+        Content::PhrasingAnd(allowed)
+            if !is_phrasing_content(child_spec.name) && !allowed.contains(&child_spec.name) =>
+        {
+            return invalid(ContentViolation::PhrasingOnly)
+        }
+        Content::Only(allowed)
+            if !allowed.contains(&child_spec.name)
+                && !matches!(child_spec.name, "script" | "template") =>
+        {
+            return invalid(ContentViolation::NotListed(allowed))
+        }
+        _ => {}
+    }
+
+    if spec.no_interactive && is_interactive_content(child_spec.name) {
+        return invalid(ContentViolation::Interactive);
+    }
+    if spec.forbidden.contains(&child_spec.name) {
+        return invalid(ContentViolation::Forbidden);
+    }
+    Ok(())
+}
+
+/// Returns true if `attribute` is allowed on the element `tag`.
+///
+/// Global, `data-*` and `aria-*` attributes are allowed on every element, as are all attributes
+/// of elements which aren't in the database. Event handler attributes (`on*`) aren't checked, and
+/// a few attributes outside of the spec which browsers accept, like `value`, are tolerated.
+pub fn is_allowed_attribute(tag: &str, attribute: &str) -> bool {
+    let spec = match element_spec(tag) {
+        Some(spec) => spec,
+        None => return true,
+    };
+    let attribute = attribute.to_ascii_lowercase();
+    let attribute = attribute.as_str();
+
+    attribute.starts_with("data-")
+        || attribute.starts_with("aria-")
+        || attribute.starts_with("on")
+        || GLOBAL_ATTRIBUTES.contains(&attribute)
+        || spec.attributes.contains(&attribute)
+        || TOLERATED_ATTRIBUTES
+            .iter()
+            .any(|&(tag, name)| name == attribute && (tag.is_none() || tag == Some(spec.name)))
+}
+
+/// Returns the attribute of the element `tag` whose name is closest to `attribute`, if one is
+/// likely to be what was meant.
+pub fn suggest_attribute(tag: &str, attribute: &str) -> Option<&'static str> {
+    let spec = element_spec(tag)?;
+    crate::closest_match(
+        attribute,
+        spec.attributes.iter().chain(GLOBAL_ATTRIBUTES).copied(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn elements_are_sorted() {
+        for pair in ELEMENTS.windows(2) {
+            assert!(
+                pair[0].name < pair[1].name,
+                "{} >= {}",
+                pair[0].name,
+                pair[1].name
+            );
+        }
+    }
+
+    #[test]
+    fn valid_children() {
+        assert!(check_child("div", "p").is_ok());
+        assert!(check_child("p", "span").is_ok());
+        assert!(check_child("ul", "li").is_ok());
+        assert!(check_child("ul", "template").is_ok());
+        assert!(check_child("table", "tbody").is_ok());
+        assert!(check_child("a", "div").is_ok());
+        assert!(check_child("p", "my-element").is_ok());
+        assert!(check_child("my-element", "li").is_ok());
+        assert!(check_child("DIV", "P").is_ok());
+        assert!(check_child("ruby", "rt").is_ok());
+        assert!(check_child("ruby", "rp").is_ok());
+        assert!(check_child("ruby", "span").is_ok());
+    }
+
+    #[test]
+    fn invalid_children() {
+        let reason = |parent, child| check_child(parent, child).unwrap_err().reason;
+        assert_eq!(reason("p", "div"), ContentViolation::PhrasingOnly);
+        assert_eq!(reason("ruby", "div"), ContentViolation::PhrasingOnly);
+        assert_eq!(reason("ul", "span"), ContentViolation::NotListed(&["li"]));
+        assert_eq!(reason("a", "a"), ContentViolation::Interactive);
+        assert_eq!(reason("button", "input"), ContentViolation::Interactive);
+        assert_eq!(reason("form", "form"), ContentViolation::Forbidden);
+        assert_eq!(reason("header", "footer"), ContentViolation::Forbidden);
+        assert_eq!(reason("dt", "h2"), ContentViolation::Forbidden);
+        assert_eq!(reason("option", "span"), ContentViolation::TextOnly);
+        assert_eq!(reason("br", "span"), ContentViolation::Void);
+    }
+
+    #[test]
+    fn attribute_suggestions() {
+        assert_eq!(suggest_attribute("a", "herf"), Some("href"));
+        assert_eq!(
+            suggest_attribute("input", "palceholder"),
+            Some("placeholder")
+        );
+        assert_eq!(suggest_attribute("div", "clas"), Some("class"));
+        assert_eq!(suggest_attribute("div", "onclick"), None);
+        assert_eq!(suggest_attribute("my-element", "clas"), None);
+    }
+
+    #[test]
+    fn invalid_child_message() {
+        let err = check_child("ul", "span").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "`<span>` is not allowed inside `<ul>`: `<ul>` can only contain `<li>`"
+        );
+    }
+
+    #[test]
+    fn attributes() {
+        assert!(is_allowed_attribute("a", "href"));
+        assert!(is_allowed_attribute("input", "placeholder"));
+        assert!(is_allowed_attribute("div", "id"));
+        assert!(is_allowed_attribute("div", "data-id"));
+        assert!(is_allowed_attribute("div", "aria-label"));
+        assert!(is_allowed_attribute("my-element", "anything"));
+        assert!(!is_allowed_attribute("input", "href"));
+        assert!(is_allowed_attribute("div", "value"));
+        assert!(is_allowed_attribute("a", "media"));
+        assert!(!is_allowed_attribute("div", "media"));
+    }
+}
@@ -4,15 +4,270 @@
 use std::ops::Deref;
 
 use crate::html::{Component, NodeRef, Scope, Scoped};
-use crate::utils::document;
+use crate::utils::{document, owner_document};
+use std::cell::Cell;
+use std::fmt;
+use std::marker::PhantomData;
+use std::panic::PanicInfo;
 use std::rc::Rc;
-use web_sys::Element;
+use web_sys::{Element, EventTarget, Node, ShadowRoot};
+
+thread_local! {
+    static NEXT_APP_ID: Cell<u32> = Cell::new(0);
+}
+
+/// Settings of a running app, shared by the scopes of all its components.
+#[derive(Debug)]
+pub(crate) struct AppConfig {
+    /// Identifies the app in the event listener registry
+    pub(crate) id: u32,
+    /// Whether events bubble up the DOM tree during delegation
+    pub(crate) event_bubbling: bool,
+    /// The node event listeners are delegated to. Defaults to the document body.
+    pub(crate) delegation_root: Option<Node>,
+}
+
+impl AppConfig {
+    fn new(event_bubbling: bool, delegation_root: Option<Node>) -> Self {
+        Self {
+            id: NEXT_APP_ID.with(|id| id.replace(id.get() + 1)),
+            event_bubbling,
+            delegation_root,
+        }
+    }
+
+    /// Returns the target global event handlers of the app are registered on.
+    pub(crate) fn delegation_target(&self) -> EventTarget {
+        match &self.delegation_root {
+            Some(root) => root.clone().into(),
+            None => document().body().expect("no body node found").into(),
+        }
+    }
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self::new(
+            crate::virtual_dom::listeners::default_event_bubbling(),
+            None,
+        )
+    }
+}
+
+/// Where an app is mounted.
+enum MountPoint {
+    Body,
+    Element(Element),
+    ShadowRoot(ShadowRoot),
+    AsBody,
+}
+
+/// How an app sets the panic hook.
+enum PanicHook {
+    Default,
+    Keep,
+    Custom(Box<dyn Fn(&PanicInfo<'_>) + Sync + Send + 'static>),
+}
+
+/// Entry point for configuring and starting an app.
+///
+/// # Example
+/// ```rust
+/// # use yew::prelude::*;
+/// # use yew::App;
+/// # struct Model;
+/// # impl Component for Model {
+/// #     type Message = ();
+/// #     type Properties = ();
+/// #     fn create(_ctx: &Context<Self>) -> Self { Model }
+/// #     fn view(&self, _ctx: &Context<Self>) -> Html { html! {} }
+/// # }
+/// # fn dont_execute() {
+/// let element = yew::utils::document().get_element_by_id("widget").unwrap();
+/// let app = App::<Model>::builder()
+///     .mount_point(element)
+///     .event_bubbling(false)
+///     .mount();
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct App<COMP: Component> {
+    _component: PhantomData<COMP>,
+}
+
+impl<COMP> App<COMP>
+where
+    COMP: Component,
+{
+    /// Creates a builder for an app whose root has the default properties.
+    pub fn builder() -> AppBuilder<COMP>
+    where
+        COMP::Properties: Default,
+    {
+        Self::builder_with_props(COMP::Properties::default())
+    }
+
+    /// Creates a builder for an app whose root has the given properties.
+    pub fn builder_with_props(props: COMP::Properties) -> AppBuilder<COMP> {
+        AppBuilder {
+            props,
+            mount_point: MountPoint::Body,
+            event_bubbling: crate::virtual_dom::listeners::default_event_bubbling(),
+            panic_hook: PanicHook::Default,
+            delegation_root: None,
+        }
+    }
+}
+
+/// Configures an app before it is mounted. Created by [`App::builder`].
+pub struct AppBuilder<COMP: Component> {
+    props: COMP::Properties,
+    mount_point: MountPoint,
+    event_bubbling: bool,
+    panic_hook: PanicHook,
+    delegation_root: Option<Node>,
+}
+
+impl<COMP: Component> fmt::Debug for AppBuilder<COMP> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("AppBuilder<_>")
+    }
+}
+
+impl<COMP> AppBuilder<COMP>
+where
+    COMP: Component,
+{
+    /// Sets the properties of the root component.
+    pub fn props(mut self, props: COMP::Properties) -> Self {
+        self.props = props;
+        self
+    }
+
+    /// Mounts the app into `element` instead of the document body.
+    ///
+    /// Anything inside the element is removed when the app is mounted.
+    pub fn mount_point(mut self, element: Element) -> Self {
+        self.mount_point = MountPoint::Element(element);
+        self
+    }
+
+    /// Mounts the app inside a shadow root, which isolates the styles of the app from the rest of
+    /// the page.
+    ///
+    /// The app is rendered into a `<div>` that is appended to `root`, and events are delegated to
+    /// `root` itself.
+    pub fn mount_in_shadow_root(mut self, root: ShadowRoot) -> Self {
+        self.mount_point = MountPoint::ShadowRoot(root);
+        self
+    }
+
+    /// Replaces the body element with the root component, which has to render a body element at
+    /// the root of its `view`. Use this when you need to manipulate the body element, for
+    /// example to add or remove app-wide CSS classes.
+    pub fn mount_as_body(mut self) -> Self {
+        self.mount_point = MountPoint::AsBody;
+        self
+    }
+
+    /// Sets whether events bubble up the DOM tree, calling any matching callbacks of this app.
+    ///
+    /// Bubbling is enabled by default. Disabling it can lead to substantial improvements in event
+    /// handling performance.
+    pub fn event_bubbling(mut self, bubble: bool) -> Self {
+        self.event_bubbling = bubble;
+        self
+    }
+
+    /// Sets the node the event listeners of this app are delegated to.
+    ///
+    /// Events are only handled once they reach this node, so it has to contain the mount point.
+    /// Defaults to the shadow root the app is mounted in, or otherwise to the body of the
+    /// document that owns the mount point. Apps mounted as body default to the document element.
+    pub fn delegation_root(mut self, root: impl Into<Node>) -> Self {
+        self.delegation_root = Some(root.into());
+        self
+    }
+
+    /// Installs `hook` as the panic hook when the app is mounted.
+    ///
+    /// Note that the panic hook is shared by everything running in the same thread. By default,
+    /// the first app that is mounted installs a hook which logs panics to the browser console,
+    /// unless a hook was set with [`set_custom_panic_hook`](crate::set_custom_panic_hook).
+    pub fn panic_hook(mut self, hook: Box<dyn Fn(&PanicInfo<'_>) + Sync + Send + 'static>) -> Self {
+        self.panic_hook = PanicHook::Custom(hook);
+        self
+    }
+
+    /// Leaves the current panic hook untouched when the app is mounted.
+    pub fn keep_panic_hook(mut self) -> Self {
+        self.panic_hook = PanicHook::Keep;
+        self
+    }
+
+    /// Mounts the app.
+    pub fn mount(self) -> AppHandle<COMP> {
+        match self.panic_hook {
+            PanicHook::Default => crate::set_default_panic_hook(),
+            PanicHook::Keep => {}
+            PanicHook::Custom(hook) => crate::set_custom_panic_hook(hook),
+        }
+
+        let props = Rc::new(self.props);
+        match self.mount_point {
+            MountPoint::Body => {
+                let config = AppConfig::new(self.event_bubbling, self.delegation_root);
+                let body = document().body().expect("no body node found").into();
+                AppHandle::mount_with_props(body, props, Rc::new(config))
+            }
+            MountPoint::Element(element) => {
+                let delegation_root = self
+                    .delegation_root
+                    .or_else(|| default_delegation_root(&element));
+                let config = AppConfig::new(self.event_bubbling, delegation_root);
+                AppHandle::mount_with_props(element, props, Rc::new(config))
+            }
+            MountPoint::ShadowRoot(root) => {
+                let container = owner_document(&root)
+                    .create_element("div")
+                    .expect("can't create the app container");
+                root.append_child(&container)
+                    .expect("can't append the app container to the shadow root");
+                let delegation_root = self.delegation_root.unwrap_or_else(|| root.into());
+                let config = AppConfig::new(self.event_bubbling, Some(delegation_root));
+                AppHandle::mount_with_props(container, props, Rc::new(config))
+            }
+            MountPoint::AsBody => {
+                // The original body is removed, so events are delegated to the document element
+                let delegation_root = self
+                    .delegation_root
+                    .or_else(|| document().document_element().map(Into::into));
+                let config = AppConfig::new(self.event_bubbling, delegation_root);
+                AppHandle::mount_as_body_with_props(props, Rc::new(config))
+            }
+        }
+    }
+}
 
 /// An instance of an application.
 #[derive(Debug)]
 pub struct AppHandle<COMP: Component> {
     /// `Scope` holder
     pub(crate) scope: Scope<COMP>,
+    node_ref: NodeRef,
+}
+
+/// Events inside a shadow root are retargeted to its host once they leave it, so they have to be
+/// handled on the shadow root. Otherwise they are handled on the body of the owner document, which
+/// is not the current document inside an iframe.
+fn default_delegation_root(element: &Element) -> Option<Node> {
+    let root = element.get_root_node();
+    // Checking the node type rather than the class also works for nodes from another realm
+    if root.node_type() == Node::DOCUMENT_FRAGMENT_NODE {
+        Some(root)
+    } else {
+        owner_document(element).body().map(Into::into)
+    }
 }
 
 impl<COMP> AppHandle<COMP>
@@ -23,13 +278,18 @@ where
     /// similarly to the `program` function in Elm. You should provide an initial model, `update`
     /// function which will update the state of the model and a `view` function which
     /// will render the model to a virtual DOM tree.
-    pub(crate) fn mount_with_props(element: Element, props: Rc<COMP::Properties>) -> Self {
+    pub(crate) fn mount_with_props(
+        element: Element,
+        props: Rc<COMP::Properties>,
+        config: Rc<AppConfig>,
+    ) -> Self {
         clear_element(&element);
         let app = Self {
-            scope: Scope::new(None),
+            scope: Scope::new_root(config),
+            node_ref: NodeRef::default(),
         };
         app.scope
-            .mount_in_place(element, NodeRef::default(), NodeRef::default(), props);
+            .mount_in_place(element, NodeRef::default(), app.node_ref.clone(), props);
 
         app
     }
@@ -38,14 +298,37 @@ where
     /// has a body element at the root of the HTML generated by its `view` method. Use this method
     /// when you need to manipulate the body element. For example, adding/removing app-wide
     /// CSS classes of the body element.
-    pub(crate) fn mount_as_body_with_props(props: Rc<COMP::Properties>) -> Self {
+    pub(crate) fn mount_as_body_with_props(
+        props: Rc<COMP::Properties>,
+        config: Rc<AppConfig>,
+    ) -> Self {
         let html_element = document().document_element().unwrap();
         let body_element = document().body().expect("no body node found");
         html_element
             .remove_child(&body_element)
             .expect("can't remove body child");
 
-        Self::mount_with_props(html_element, props)
+        Self::mount_with_props(html_element, props, config)
+    }
+
+    /// Passes new properties to the root component.
+    ///
+    /// This runs the same flow as a parent re-rendering a child component: if the properties
+    /// differ from the current ones, [`Component::changed`] is called and the root is re-rendered
+    /// if it returns `true`. Properties that arrive while a render of the root is still pending
+    /// are applied right after that render.
+    pub fn update_props(&self, props: COMP::Properties) {
+        self.scope
+            .reuse(Rc::new(props), self.node_ref.clone(), NodeRef::default());
+    }
+
+    /// Moves the app into another host element.
+    ///
+    /// The component and its state are kept. Anything inside `element` is removed first, like
+    /// when the app was mounted.
+    pub fn remount(&self, element: Element) {
+        clear_element(&element);
+        self.scope.reparent(element);
     }
 
     /// Schedule the app for destruction
@@ -71,3 +354,161 @@ fn clear_element(element: &Element) {
         element.remove_child(&child).expect("can't remove a child");
     }
 }
+
+#[cfg(test)]
+mod tests {
+    extern crate self as yew;
+
+    use super::*;
+    use crate::scheduler::{self, Runnable};
+    use crate::{html, Context, Html, Properties};
+    use std::cell::RefCell;
+    #[cfg(feature = "wasm_test")]
+    use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+    #[cfg(feature = "wasm_test")]
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[derive(Clone, Properties, PartialEq)]
+    struct Props {
+        value: u32,
+        lifecycle: Rc<RefCell<Vec<String>>>,
+    }
+
+    struct Comp;
+
+    impl Component for Comp {
+        type Message = ();
+        type Properties = Props;
+
+        fn create(_ctx: &Context<Self>) -> Self {
+            Comp
+        }
+
+        fn changed(&mut self, ctx: &Context<Self>) -> bool {
+            let props = ctx.props();
+            props
+                .lifecycle
+                .borrow_mut()
+                .push(format!("changed({})", props.value));
+            true
+        }
+
+        fn view(&self, ctx: &Context<Self>) -> Html {
+            let props = ctx.props();
+            props
+                .lifecycle
+                .borrow_mut()
+                .push(format!("view({})", props.value));
+            html! { <span>{ props.value }</span> }
+        }
+    }
+
+    /// Runs `f` from within the scheduler, so everything it schedules stays pending until it
+    /// returns.
+    fn in_scheduler(f: impl FnOnce() + 'static) {
+        struct Task<F>(F);
+        impl<F: FnOnce()> Runnable for Task<F> {
+            fn run(self: Box<Self>) {
+                (self.0)()
+            }
+        }
+        scheduler::push(Box::new(Task(f)));
+    }
+
+    fn setup() -> (Element, Rc<RefCell<Vec<String>>>) {
+        let element = document().create_element("div").unwrap();
+        (element, Rc::default())
+    }
+
+    fn mount(element: &Element, props: Props) -> AppHandle<Comp> {
+        AppHandle::mount_with_props(element.clone(), Rc::new(props), Rc::default())
+    }
+
+    fn props(value: u32, lifecycle: &Rc<RefCell<Vec<String>>>) -> Props {
+        Props {
+            value,
+            lifecycle: Rc::clone(lifecycle),
+        }
+    }
+
+    #[test]
+    fn update_props_rerenders_root() {
+        let (element, lifecycle) = setup();
+        let app = mount(&element, props(1, &lifecycle));
+        assert_eq!(element.inner_html(), "<span>1</span>");
+
+        app.update_props(props(2, &lifecycle));
+        assert_eq!(element.inner_html(), "<span>2</span>");
+
+        // Equal props don't trigger `changed`
+        app.update_props(props(2, &lifecycle));
+        assert_eq!(
+            &lifecycle.borrow()[..],
+            &["view(1)", "changed(2)", "view(2)"]
+        );
+    }
+
+    #[test]
+    fn update_props_while_first_render_pending() {
+        let (element, lifecycle) = setup();
+        {
+            let element = element.clone();
+            let lifecycle = Rc::clone(&lifecycle);
+            in_scheduler(move || {
+                let app = mount(&element, props(1, &lifecycle));
+                app.update_props(props(2, &lifecycle));
+                assert!(lifecycle.borrow().is_empty());
+            });
+        }
+
+        assert_eq!(element.inner_html(), "<span>2</span>");
+        assert_eq!(
+            &lifecycle.borrow()[..],
+            &["view(1)", "changed(2)", "view(2)"]
+        );
+    }
+
+    #[test]
+    fn update_props_while_render_pending() {
+        let (element, lifecycle) = setup();
+        let app = Rc::new(mount(&element, props(1, &lifecycle)));
+        lifecycle.borrow_mut().clear();
+
+        {
+            let app = Rc::clone(&app);
+            let lifecycle = Rc::clone(&lifecycle);
+            in_scheduler(move || {
+                app.update_props(props(2, &lifecycle));
+                app.update_props(props(3, &lifecycle));
+            });
+        }
+
+        // The second update waits until the render caused by the first one is done
+        assert_eq!(element.inner_html(), "<span>3</span>");
+        assert_eq!(
+            &lifecycle.borrow()[..],
+            &["changed(2)", "view(2)", "changed(3)", "view(3)"]
+        );
+    }
+
+    #[test]
+    fn remount_moves_app() {
+        let (first, lifecycle) = setup();
+        let second = document().create_element("div").unwrap();
+        second.set_inner_html("<p>placeholder</p>");
+
+        let app = mount(&first, props(1, &lifecycle));
+        app.remount(second.clone());
+        assert_eq!(first.inner_html(), "");
+        assert_eq!(second.inner_html(), "<span>1</span>");
+
+        app.update_props(props(2, &lifecycle));
+        assert_eq!(first.inner_html(), "");
+        assert_eq!(second.inner_html(), "<span>2</span>");
+        assert_eq!(
+            &lifecycle.borrow()[..],
+            &["view(1)", "changed(2)", "view(2)"]
+        );
+    }
+}
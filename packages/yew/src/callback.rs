@@ -5,9 +5,16 @@
 //! - [Timer](https://github.com/yewstack/yew/tree/master/examples/timer)
 
 use crate::html::ImplicitClone;
+use gloo::console;
+use gloo::timers::callback::Timeout;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 use std::cell::RefCell;
 use std::fmt;
+use std::marker::PhantomData;
 use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
 
 /// Universal callback wrapper.
 /// <aside class="warning">
@@ -16,11 +23,15 @@ use std::rc::Rc;
 /// Callbacks should be used from JS callbacks or `setTimeout` calls.
 /// </aside>
 /// An `Rc` wrapper is used to make it cloneable.
-pub enum Callback<IN> {
+///
+/// The callback's function can return a value of type `OUT` to the caller of
+/// [`emit`](Callback::emit), which makes it possible to pass validators or formatters to child
+/// components. Most callbacks, including all event listeners, return nothing.
+pub enum Callback<IN, OUT = ()> {
     /// A callback which can be called multiple times with optional modifier flags
     Callback {
         /// A callback which can be called multiple times
-        cb: Rc<dyn Fn(IN)>,
+        cb: Rc<dyn Fn(IN) -> OUT>,
 
         /// Setting `passive` to [Some] explicitly makes the event listener passive or not.
         /// Yew sets sane defaults depending on the type of the listener.
@@ -31,12 +42,12 @@ pub enum Callback<IN> {
 
     /// A callback which can only be called once. The callback will panic if it is
     /// called more than once.
-    CallbackOnce(Rc<CallbackOnce<IN>>),
+    CallbackOnce(Rc<CallbackOnce<IN, OUT>>),
 }
 
-type CallbackOnce<IN> = RefCell<Option<Box<dyn FnOnce(IN)>>>;
+type CallbackOnce<IN, OUT> = RefCell<Option<Box<dyn FnOnce(IN) -> OUT>>>;
 
-impl<IN, F: Fn(IN) + 'static> From<F> for Callback<IN> {
+impl<IN, OUT, F: Fn(IN) -> OUT + 'static> From<F> for Callback<IN, OUT> {
     fn from(func: F) -> Self {
         Callback::Callback {
             cb: Rc::new(func),
@@ -45,7 +56,7 @@ impl<IN, F: Fn(IN) + 'static> From<F> for Callback<IN> {
     }
 }
 
-impl<IN> Clone for Callback<IN> {
+impl<IN, OUT> Clone for Callback<IN, OUT> {
     fn clone(&self) -> Self {
         match self {
             Callback::Callback { cb, passive } => Callback::Callback {
@@ -58,8 +69,8 @@ impl<IN> Clone for Callback<IN> {
 }
 
 #[allow(clippy::vtable_address_comparisons)]
-impl<IN> PartialEq for Callback<IN> {
-    fn eq(&self, other: &Callback<IN>) -> bool {
+impl<IN, OUT> PartialEq for Callback<IN, OUT> {
+    fn eq(&self, other: &Callback<IN, OUT>) -> bool {
         match (&self, &other) {
             (Callback::CallbackOnce(cb), Callback::CallbackOnce(other_cb)) => {
                 Rc::ptr_eq(cb, other_cb)
@@ -76,7 +87,7 @@ impl<IN> PartialEq for Callback<IN> {
     }
 }
 
-impl<IN> fmt::Debug for Callback<IN> {
+impl<IN, OUT> fmt::Debug for Callback<IN, OUT> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let data = match self {
             Callback::Callback { .. } => "Callback<_>",
@@ -87,9 +98,9 @@ impl<IN> fmt::Debug for Callback<IN> {
     }
 }
 
-impl<IN> Callback<IN> {
-    /// This method calls the callback's function.
-    pub fn emit(&self, value: IN) {
+impl<IN, OUT> Callback<IN, OUT> {
+    /// This method calls the callback's function and returns its result.
+    pub fn emit(&self, value: IN) -> OUT {
         match self {
             Callback::Callback { cb, .. } => cb(value),
             Callback::CallbackOnce(rc) => {
@@ -97,7 +108,7 @@ impl<IN> Callback<IN> {
                 let f = cb.expect("callback contains `FnOnce` which has already been used");
                 f(value)
             }
-        };
+        }
     }
 
     /// Creates a callback from an `FnOnce`. The programmer is responsible for ensuring
@@ -105,38 +116,328 @@ impl<IN> Callback<IN> {
     /// will panic.
     pub fn once<F>(func: F) -> Self
     where
-        F: FnOnce(IN) + 'static,
+        F: FnOnce(IN) -> OUT + 'static,
     {
         Callback::CallbackOnce(Rc::new(RefCell::new(Some(Box::new(func)))))
     }
+}
 
+impl<IN, OUT: Default> Callback<IN, OUT> {
     /// Creates a "no-op" callback which can be used when it is not suitable to use an
-    /// `Option<Callback>`.
+    /// `Option<Callback>`. It returns the default value of `OUT`.
     pub fn noop() -> Self {
-        Self::from(|_| {})
+        Self::from(|_| OUT::default())
     }
 }
 
-impl<IN> Default for Callback<IN> {
+impl<IN, OUT: Default> Default for Callback<IN, OUT> {
     fn default() -> Self {
         Self::noop()
     }
 }
 
-impl<IN: 'static> Callback<IN> {
-    /// Changes the input type of the callback to another.
-    /// Works like the `map` method but in the opposite direction.
-    pub fn reform<F, T>(&self, func: F) -> Callback<T>
+impl<IN: 'static, OUT: 'static> Callback<IN, OUT> {
+    /// Changes the input type of the callback to another by converting every input with `func`
+    /// before it's passed on to this callback.
+    pub fn reform<F, T>(&self, func: F) -> Callback<T, OUT>
     where
         F: Fn(T) -> IN + 'static,
     {
         let this = self.clone();
         let func = move |input| {
             let output = func(input);
-            this.emit(output);
+            this.emit(output)
+        };
+        Callback::from(func)
+    }
+
+    /// Changes the output type of the callback to another by converting every result of this
+    /// callback with `func`. This is the counterpart of [`reform`](Self::reform).
+    pub fn map<F, T>(&self, func: F) -> Callback<IN, T>
+    where
+        F: Fn(OUT) -> T + 'static,
+    {
+        let this = self.clone();
+        Callback::from(move |input| func(this.emit(input)))
+    }
+}
+
+impl<IN: 'static> Callback<IN> {
+    /// Changes the input type of the callback to another, like [`reform`](Self::reform), but
+    /// only calls the callback if `func` returns [`Some`].
+    ///
+    /// ```
+    /// # use yew::{Callback, KeyboardEvent};
+    /// # fn example(on_submit: Callback<()>) {
+    /// let onkeypress = on_submit.filter_reform(|e: KeyboardEvent| {
+    ///     if e.key() == "Enter" {
+    ///         Some(())
+    ///     } else {
+    ///         None
+    ///     }
+    /// });
+    /// # }
+    /// ```
+    pub fn filter_reform<F, T>(&self, func: F) -> Callback<T>
+    where
+        F: Fn(T) -> Option<IN> + 'static,
+    {
+        let this = self.clone();
+        let func = move |input| {
+            if let Some(output) = func(input) {
+                this.emit(output);
+            }
+        };
+        Callback::from(func)
+    }
+
+    /// Creates a callback which calls this one once it hasn't been called for `millis`
+    /// milliseconds, with the latest input.
+    ///
+    /// The pending call is cancelled when the returned callback and all of its clones are
+    /// dropped. Like any other callback it is only equal to its own clones, so create it once,
+    /// for example in `create`, instead of on every render.
+    pub fn debounce(&self, millis: u32) -> Callback<IN> {
+        let this = self.clone();
+        let timeout = RefCell::new(None);
+        let func = move |input| {
+            let this = this.clone();
+            // Replacing the timeout cancels the previous one
+            *timeout.borrow_mut() = Some(Timeout::new(millis, move || this.emit(input)));
         };
         Callback::from(func)
     }
+
+    /// Creates a callback which calls this one at most once every `millis` milliseconds.
+    ///
+    /// The first call goes through right away. Calls made while throttled are dropped except
+    /// for the last one, which is made when the interval ends.
+    ///
+    /// Like [`debounce`](Self::debounce), the returned callback has to be kept alive for
+    /// throttled calls to be made.
+    pub fn throttle(&self, millis: u32) -> Callback<IN> {
+        let this = self.clone();
+        let state = Rc::new(RefCell::new(Throttle {
+            timeout: None,
+            pending: None,
+        }));
+        let func = move |input| {
+            let throttled = state.borrow().timeout.is_some();
+            if throttled {
+                state.borrow_mut().pending = Some(input);
+            } else {
+                this.emit(input);
+                Throttle::start(&state, this.clone(), millis);
+            }
+        };
+        Callback::from(func)
+    }
+}
+
+struct Throttle<IN> {
+    timeout: Option<Timeout>,
+    pending: Option<IN>,
+}
+
+impl<IN: 'static> Throttle<IN> {
+    fn start(state: &Rc<RefCell<Self>>, callback: Callback<IN>, millis: u32) {
+        // Only a weak reference is kept so that dropping the callback cancels the timeout
+        let weak = Rc::downgrade(state);
+        let timeout = Timeout::new(millis, move || {
+            let state = match weak.upgrade() {
+                Some(state) => state,
+                None => return,
+            };
+            let pending = {
+                let mut state = state.borrow_mut();
+                state.timeout = None;
+                state.pending.take()
+            };
+            if let Some(input) = pending {
+                callback.emit(input);
+                Throttle::start(&state, callback, millis);
+            }
+        });
+        state.borrow_mut().timeout = Some(timeout);
+    }
+}
+
+impl<IN: JsCast + 'static> Callback<IN> {
+    /// Turns the callback into a JavaScript function which can be passed to JS code.
+    ///
+    /// The argument the function is called with is converted with [`JsCast`]. Calls with an
+    /// argument of the wrong type are logged and ignored. The function stops calling the
+    /// callback once the returned [`JsFunction`] is dropped.
+    pub fn into_js_function(self) -> JsFunction {
+        JsFunction::new(move |value: JsValue| {
+            match value.dyn_into::<IN>() {
+                Ok(input) => self.emit(input),
+                Err(value) => console::error!("callback called with an unexpected value", value),
+            }
+            JsValue::UNDEFINED
+        })
+    }
+}
+
+impl<IN, OUT> ImplicitClone for Callback<IN, OUT> {}
+
+#[wasm_bindgen(inline_js = r#"
+const RELEASE = Symbol("release");
+
+export function guard_function(f) {
+    const guarded = function (...args) {
+        if (f !== null) {
+            return f.apply(this, args);
+        }
+    };
+    guarded[RELEASE] = () => {
+        f = null;
+    };
+    return guarded;
+}
+
+export function release_function(guarded) {
+    guarded[RELEASE]();
+}
+"#)]
+extern "C" {
+    fn guard_function(function: &js_sys::Function) -> js_sys::Function;
+    fn release_function(function: &js_sys::Function);
+}
+
+/// A JavaScript function backed by a Rust closure.
+///
+/// The handle owns the closure. Once it is dropped the function turns into a no-op, so JS code
+/// that holds on to it can't call into freed memory.
+pub struct JsFunction {
+    function: js_sys::Function,
+    closure: Option<Closure<dyn Fn(JsValue) -> JsValue>>,
+}
+
+impl JsFunction {
+    fn new<F>(func: F) -> Self
+    where
+        F: Fn(JsValue) -> JsValue + 'static,
+    {
+        let closure = Closure::wrap(Box::new(func) as Box<dyn Fn(JsValue) -> JsValue>);
+        Self {
+            function: guard_function(closure.as_ref().unchecked_ref()),
+            closure: Some(closure),
+        }
+    }
+
+    /// The JavaScript function.
+    pub fn as_function(&self) -> &js_sys::Function {
+        &self.function
+    }
+
+    /// Leaks the closure so that the function stays valid for the rest of the program.
+    pub fn forget(mut self) -> js_sys::Function {
+        if let Some(closure) = self.closure.take() {
+            closure.forget();
+        }
+        self.function.clone()
+    }
+}
+
+impl Drop for JsFunction {
+    fn drop(&mut self) {
+        if self.closure.is_some() {
+            release_function(&self.function);
+        }
+    }
+}
+
+impl AsRef<JsValue> for JsFunction {
+    fn as_ref(&self) -> &JsValue {
+        &self.function
+    }
+}
+
+impl fmt::Debug for JsFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("JsFunction")
+    }
 }
 
-impl<T> ImplicitClone for Callback<T> {}
+/// A typed JavaScript function backed by a Rust closure.
+///
+/// Unlike [`Callback::into_js_function`], the argument and the return value are converted with
+/// `serde`, so plain JS objects can be passed to and returned from Rust types. Calls with an
+/// argument that can't be decoded are logged and return `undefined`.
+///
+/// Like [`JsFunction`], the function becomes a no-op once the handle is dropped.
+pub struct JsCallback<IN, OUT = ()> {
+    function: JsFunction,
+    _marker: PhantomData<fn(IN) -> OUT>,
+}
+
+impl<IN, OUT> JsCallback<IN, OUT>
+where
+    IN: DeserializeOwned + 'static,
+    OUT: Serialize + 'static,
+{
+    /// Creates a JavaScript function which calls `func`.
+    pub fn new<F>(func: F) -> Self
+    where
+        F: Fn(IN) -> OUT + 'static,
+    {
+        let function = JsFunction::new(move |value: JsValue| {
+            let input = match serde_wasm_bindgen::from_value(value) {
+                Ok(input) => input,
+                Err(err) => {
+                    console::error!(format!("can't decode callback argument: {}", err));
+                    return JsValue::UNDEFINED;
+                }
+            };
+            serde_wasm_bindgen::to_value(&func(input)).unwrap_or_else(|err| {
+                console::error!(format!("can't encode callback result: {}", err));
+                JsValue::UNDEFINED
+            })
+        });
+        Self {
+            function,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<IN, OUT> JsCallback<IN, OUT> {
+    /// The JavaScript function.
+    pub fn as_function(&self) -> &js_sys::Function {
+        self.function.as_function()
+    }
+
+    /// Leaks the closure so that the function stays valid for the rest of the program.
+    pub fn forget(self) -> js_sys::Function {
+        self.function.forget()
+    }
+}
+
+impl<IN, OUT> From<Callback<IN, OUT>> for JsCallback<IN, OUT>
+where
+    IN: DeserializeOwned + 'static,
+    OUT: Serialize + 'static,
+{
+    fn from(callback: Callback<IN, OUT>) -> Self {
+        Self::new(move |input| callback.emit(input))
+    }
+}
+
+impl<IN, OUT> From<JsCallback<IN, OUT>> for JsFunction {
+    fn from(callback: JsCallback<IN, OUT>) -> Self {
+        callback.function
+    }
+}
+
+impl<IN, OUT> AsRef<JsValue> for JsCallback<IN, OUT> {
+    fn as_ref(&self) -> &JsValue {
+        self.function.as_ref()
+    }
+}
+
+impl<IN, OUT> fmt::Debug for JsCallback<IN, OUT> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("JsCallback<_>")
+    }
+}
@@ -0,0 +1,399 @@
+//! This module contains [`register_custom_element`], which turns a component into a
+//! [custom element](https://developer.mozilla.org/en-US/docs/Web/Web_Components/Using_custom_elements)
+//! that can be used from plain HTML or from other frameworks.
+//!
+//! Each instance of the element mounts the component in its own shadow root when it's connected
+//! to the document and destroys it when it's disconnected. Observed attributes and properties of
+//! the element are turned into the component's properties with
+//! [`CustomElementProperties::from_host`], which is called again every time one of them changes.
+//!
+//! ```
+//! # use yew::prelude::*;
+//! use yew::custom_element::{register_custom_element, CustomElementHost, CustomElementProperties};
+//!
+//! #[derive(Clone, PartialEq, Properties)]
+//! pub struct Props {
+//!     pub count: i32,
+//!     pub on_increment: Callback<i32>,
+//! }
+//!
+//! impl CustomElementProperties for Props {
+//!     const OBSERVED_ATTRIBUTES: &'static [&'static str] = &["count"];
+//!
+//!     fn from_host(host: &CustomElementHost) -> Self {
+//!         Self {
+//!             count: host.attribute("count").unwrap_or_default(),
+//!             // Dispatches an `increment` event with the new count as its `detail`
+//!             on_increment: host.event("increment"),
+//!         }
+//!     }
+//! }
+//!
+//! #[function_component(Counter)]
+//! fn counter(props: &Props) -> Html {
+//!     let count = props.count;
+//!     let onclick = props.on_increment.reform(move |_| count + 1);
+//!     html! { <button {onclick}>{ count }</button> }
+//! }
+//!
+//! # fn dont_execute() {
+//! register_custom_element::<Counter>("my-counter").unwrap();
+//! // <my-counter count="3"></my-counter> can now be used in the page
+//! # }
+//! ```
+
+use crate::format::FormatError;
+use crate::{App, AppHandle, Callback, Component, Properties};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{CustomEvent, CustomEventInit, HtmlElement, ShadowRootInit, ShadowRootMode};
+
+#[wasm_bindgen(inline_js = r#"
+export function define_custom_element(tag, attributes, properties, connected, disconnected, changed) {
+    class YewElement extends HTMLElement {
+        static get observedAttributes() {
+            return attributes;
+        }
+
+        connectedCallback() {
+            // Properties set before the element was upgraded shadow the accessors
+            for (const name of properties) {
+                if (Object.prototype.hasOwnProperty.call(this, name)) {
+                    const value = this[name];
+                    delete this[name];
+                    this[name] = value;
+                }
+            }
+            connected(this);
+        }
+
+        disconnectedCallback() {
+            disconnected(this);
+        }
+
+        attributeChangedCallback(name, oldValue, newValue) {
+            if (oldValue !== newValue) {
+                changed(this);
+            }
+        }
+    }
+
+    for (const name of properties) {
+        Object.defineProperty(YewElement.prototype, name, {
+            get() {
+                return this.__yew_properties ? this.__yew_properties[name] : undefined;
+            },
+            set(value) {
+                this.__yew_properties = this.__yew_properties || {};
+                this.__yew_properties[name] = value;
+                changed(this);
+            },
+        });
+    }
+
+    customElements.define(tag, YewElement);
+}
+"#)]
+extern "C" {
+    #[wasm_bindgen(catch)]
+    fn define_custom_element(
+        tag: &str,
+        attributes: js_sys::Array,
+        properties: js_sys::Array,
+        connected: &js_sys::Function,
+        disconnected: &js_sys::Function,
+        changed: &js_sys::Function,
+    ) -> Result<(), JsValue>;
+}
+
+thread_local! {
+    /// Components mounted by custom elements, by instance ID
+    static INSTANCES: RefCell<HashMap<u32, Box<dyn Instance>>> = Default::default();
+
+    /// Counter for assigning new instance IDs
+    static NEXT_ID: Cell<u32> = const { Cell::new(0) };
+
+    /// Key used to store the instance ID on the element
+    static INSTANCE_ID_PROP: JsValue = "__yew_custom_element_id".into();
+}
+
+/// An error that occurred while registering a custom element.
+#[derive(Debug, Error)]
+pub enum CustomElementError {
+    /// The browser refused to define the element, for example because the name isn't a valid
+    /// custom element name or is already taken.
+    #[error("failed to define custom element: {0}")]
+    Define(String),
+}
+
+/// Properties of a component that is registered as a custom element.
+pub trait CustomElementProperties: Properties {
+    /// The attributes of the element that are turned into properties.
+    const OBSERVED_ATTRIBUTES: &'static [&'static str];
+
+    /// The JavaScript properties of the element that are turned into properties.
+    const OBSERVED_PROPERTIES: &'static [&'static str] = &[];
+
+    /// Creates the properties from the current state of the element.
+    fn from_host(host: &CustomElementHost) -> Self;
+}
+
+/// The custom element a component is mounted in.
+#[derive(Debug)]
+pub struct CustomElementHost {
+    element: HtmlElement,
+    attributes: &'static [&'static str],
+    properties: &'static [&'static str],
+}
+
+impl CustomElementHost {
+    /// The custom element.
+    pub fn element(&self) -> &HtmlElement {
+        &self.element
+    }
+
+    /// Parses the value of the attribute `name`.
+    ///
+    /// Returns `None` if the attribute isn't set or can't be parsed.
+    pub fn attribute<T: FromStr>(&self, name: &str) -> Option<T> {
+        self.element.get_attribute(name)?.parse().ok()
+    }
+
+    /// Returns `true` if the boolean attribute `name` is present.
+    pub fn has_attribute(&self, name: &str) -> bool {
+        self.element.has_attribute(name)
+    }
+
+    /// Returns the value of the JavaScript property `name`, if it's defined.
+    pub fn property(&self, name: &str) -> Option<JsValue> {
+        js_sys::Reflect::get(&self.element, &JsValue::from_str(name))
+            .ok()
+            .filter(|value| !value.is_undefined())
+    }
+
+    /// Decodes the value of the JavaScript property `name`, which has to be JSON serializable.
+    pub fn property_as<T: DeserializeOwned>(&self, name: &str) -> Option<Result<T, FormatError>> {
+        self.property(name).map(|value| from_js(&value))
+    }
+
+    /// Decodes all observed attributes and properties at once.
+    ///
+    /// The attributes and properties form the fields of a JSON object, with dashes in attribute
+    /// names replaced by underscores. Attribute values that are valid JSON, like numbers or
+    /// booleans, are decoded as such and any other value as a string. Missing attributes and
+    /// properties are left out, so they can be filled in with `#[serde(default)]`.
+    pub fn deserialize<T: DeserializeOwned>(&self) -> Result<T, FormatError> {
+        let mut fields = serde_json::Map::new();
+        for name in self.attributes {
+            if let Some(raw) = self.element.get_attribute(name) {
+                let value = serde_json::from_str(&raw).unwrap_or(serde_json::Value::String(raw));
+                fields.insert(name.replace('-', "_"), value);
+            }
+        }
+        for name in self.properties {
+            if let Some(value) = self.property(name) {
+                fields.insert((*name).to_owned(), from_js(&value)?);
+            }
+        }
+        serde_json::from_value(serde_json::Value::Object(fields))
+            .map_err(|err| FormatError::Decode(err.to_string()))
+    }
+
+    /// Creates a callback which dispatches a
+    /// [`CustomEvent`](https://developer.mozilla.org/en-US/docs/Web/API/CustomEvent) called
+    /// `name` on the element.
+    ///
+    /// The value passed to the callback becomes the `detail` of the event. The event bubbles
+    /// and crosses shadow DOM boundaries.
+    pub fn event<T: Serialize>(&self, name: &'static str) -> Callback<T> {
+        let element = self.element.clone();
+        Callback::from(move |detail: T| {
+            let detail = match to_js(&detail) {
+                Ok(detail) => detail,
+                Err(err) => {
+                    gloo::console::error!(format!("can't dispatch `{}` event: {}", name, err));
+                    return;
+                }
+            };
+            let mut init = CustomEventInit::new();
+            init.bubbles(true).composed(true).detail(&detail);
+            if let Ok(event) = CustomEvent::new_with_event_init_dict(name, &init) {
+                let _ = element.dispatch_event(&event);
+            }
+        })
+    }
+}
+
+fn from_js<T: DeserializeOwned>(value: &JsValue) -> Result<T, FormatError> {
+    let json = js_sys::JSON::stringify(value)
+        .ok()
+        .and_then(|json| json.as_string())
+        .ok_or_else(|| FormatError::Decode("value isn't JSON serializable".into()))?;
+    serde_json::from_str(&json).map_err(|err| FormatError::Decode(err.to_string()))
+}
+
+fn to_js<T: Serialize>(value: &T) -> Result<JsValue, FormatError> {
+    let json = serde_json::to_string(value).map_err(|err| FormatError::Encode(err.to_string()))?;
+    js_sys::JSON::parse(&json).map_err(|_| FormatError::Encode("invalid JSON".into()))
+}
+
+/// A component mounted by a custom element.
+trait Instance {
+    fn update(&self, element: HtmlElement);
+    fn destroy(self: Box<Self>);
+}
+
+struct MountedApp<COMP: Component> {
+    app: AppHandle<COMP>,
+}
+
+impl<COMP> Instance for MountedApp<COMP>
+where
+    COMP: Component,
+    COMP::Properties: CustomElementProperties,
+{
+    fn update(&self, element: HtmlElement) {
+        self.app.update_props(props::<COMP>(element));
+    }
+
+    fn destroy(self: Box<Self>) {
+        self.app.destroy();
+    }
+}
+
+impl<COMP: Component> fmt::Debug for MountedApp<COMP> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("MountedApp<_>")
+    }
+}
+
+fn props<COMP>(element: HtmlElement) -> COMP::Properties
+where
+    COMP: Component,
+    COMP::Properties: CustomElementProperties,
+{
+    COMP::Properties::from_host(&CustomElementHost {
+        element,
+        attributes: <COMP::Properties as CustomElementProperties>::OBSERVED_ATTRIBUTES,
+        properties: <COMP::Properties as CustomElementProperties>::OBSERVED_PROPERTIES,
+    })
+}
+
+fn instance_id(element: &HtmlElement) -> Option<u32> {
+    INSTANCE_ID_PROP
+        .with(|prop| js_sys::Reflect::get(element, prop).ok())?
+        .as_f64()
+        .map(|id| id as u32)
+}
+
+/// Removes the instance of `element` from the registry.
+///
+/// Instances are taken out while they run, because mounting, updating and destroying components
+/// can connect and disconnect nested custom elements.
+fn take_instance(element: &HtmlElement) -> Option<(u32, Box<dyn Instance>)> {
+    let id = instance_id(element)?;
+    INSTANCES
+        .with(|instances| instances.borrow_mut().remove(&id))
+        .map(|instance| (id, instance))
+}
+
+fn connected<COMP>(element: HtmlElement)
+where
+    COMP: Component,
+    COMP::Properties: CustomElementProperties,
+{
+    if let Some((id, instance)) = take_instance(&element) {
+        // Already mounted
+        INSTANCES.with(|instances| instances.borrow_mut().insert(id, instance));
+        return;
+    }
+
+    let root = match element.shadow_root() {
+        Some(root) => {
+            while let Some(child) = root.last_child() {
+                let _ = root.remove_child(&child);
+            }
+            root
+        }
+        None => element
+            .attach_shadow(&ShadowRootInit::new(ShadowRootMode::Open))
+            .expect("can't attach a shadow root to the custom element"),
+    };
+
+    let app = App::<COMP>::builder_with_props(props::<COMP>(element.clone()))
+        .mount_in_shadow_root(root)
+        .mount();
+
+    let id = NEXT_ID.with(|id| id.replace(id.get() + 1));
+    INSTANCE_ID_PROP.with(|prop| {
+        js_sys::Reflect::set(&element, prop, &JsValue::from(id))
+            .expect("failed to set custom element instance ID")
+    });
+    INSTANCES.with(|instances| {
+        instances
+            .borrow_mut()
+            .insert(id, Box::new(MountedApp { app }))
+    });
+}
+
+fn disconnected(element: HtmlElement) {
+    if let Some((_, instance)) = take_instance(&element) {
+        instance.destroy();
+    }
+}
+
+fn changed(element: HtmlElement) {
+    if let Some((id, instance)) = take_instance(&element) {
+        instance.update(element);
+        INSTANCES.with(|instances| instances.borrow_mut().insert(id, instance));
+    }
+}
+
+/// Registers `COMP` as the custom element `tag`.
+///
+/// `tag` has to be a valid custom element name, which means it has to contain a dash. A name can
+/// only be registered once per page.
+///
+/// The component is mounted in the shadow root of every instance of the element while the
+/// instance is connected to the document. Note that moving an element to another position
+/// disconnects and reconnects it, which mounts a new component.
+pub fn register_custom_element<COMP>(tag: &str) -> Result<(), CustomElementError>
+where
+    COMP: Component,
+    COMP::Properties: CustomElementProperties,
+{
+    let names = |names: &[&str]| names.iter().copied().map(JsValue::from_str).collect();
+
+    // Custom elements can't be unregistered, so the callbacks live as long as the page
+    let connected = Closure::wrap(Box::new(connected::<COMP>) as Box<dyn Fn(HtmlElement)>);
+    let disconnected = Closure::wrap(Box::new(disconnected) as Box<dyn Fn(HtmlElement)>);
+    let changed = Closure::wrap(Box::new(changed) as Box<dyn Fn(HtmlElement)>);
+
+    define_custom_element(
+        tag,
+        names(<COMP::Properties as CustomElementProperties>::OBSERVED_ATTRIBUTES),
+        names(<COMP::Properties as CustomElementProperties>::OBSERVED_PROPERTIES),
+        connected.as_ref().unchecked_ref(),
+        disconnected.as_ref().unchecked_ref(),
+        changed.as_ref().unchecked_ref(),
+    )
+    .map_err(|err| {
+        CustomElementError::Define(
+            err.dyn_ref::<js_sys::Error>()
+                .map(|err| String::from(err.message()))
+                .unwrap_or_else(|| format!("{:?}", err)),
+        )
+    })?;
+
+    connected.forget();
+    disconnected.forget();
+    changed.forget();
+    Ok(())
+}
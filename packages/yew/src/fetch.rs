@@ -0,0 +1,467 @@
+//! This module contains an API for making HTTP requests through the browser's
+//! [Fetch API](https://developer.mozilla.org/en-US/docs/Web/API/Fetch_API).
+//!
+//! Requests and responses are described using the types of the [`http`] crate. Response bodies
+//! are decoded with a [`Format`], JSON being the default.
+//!
+//! ```
+//! # use yew::fetch::{self, FetchError, Request};
+//! # use serde::Deserialize;
+//! #[derive(Deserialize)]
+//! struct User {
+//!     name: String,
+//! }
+//!
+//! async fn user_name(id: u32) -> Result<String, FetchError> {
+//!     let request = Request::get(format!("/api/users/{}", id))
+//!         .body(())
+//!         .expect("valid request");
+//!     let response = fetch::fetch::<_, User>(request).await?;
+//!     Ok(response.into_body().name)
+//! }
+//! ```
+//!
+//! Requests are sent through a [`Transport`]. [`FetchClient`] can be constructed with a custom
+//! transport, for example a closure returning canned responses in tests.
+
+use crate::format::{Format, FormatError, Json};
+use crate::utils::window;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll};
+use thiserror::Error;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{AbortController, AbortSignal, Headers, RequestInit};
+
+pub use http::{HeaderMap, Method, Request, Response, StatusCode, Uri};
+
+/// The body of a request.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum Body {
+    /// No body.
+    #[default]
+    Empty,
+    /// A text body.
+    Text(String),
+    /// A binary body.
+    Bytes(Vec<u8>),
+    /// A body encoded with a [`Format`], sent with the format's content type.
+    Encoded {
+        /// The `Content-Type` of the encoded data.
+        content_type: &'static str,
+        /// The encoded data.
+        data: Vec<u8>,
+    },
+}
+
+impl Body {
+    /// Encodes `value` with the format `F`.
+    ///
+    /// The request is sent with `F::CONTENT_TYPE` unless it already has a `Content-Type` header.
+    pub fn encode<F: Format, T: Serialize + ?Sized>(value: &T) -> Result<Self, FormatError> {
+        F::encode(value).map(|data| Body::Encoded {
+            content_type: F::CONTENT_TYPE,
+            data,
+        })
+    }
+
+    /// Returns the content type of an [encoded](Body::encode) body.
+    pub fn content_type(&self) -> Option<&'static str> {
+        match self {
+            Body::Encoded { content_type, .. } => Some(content_type),
+            _ => None,
+        }
+    }
+}
+
+impl From<()> for Body {
+    fn from(_: ()) -> Self {
+        Body::Empty
+    }
+}
+
+impl From<String> for Body {
+    fn from(text: String) -> Self {
+        Body::Text(text)
+    }
+}
+
+impl From<&str> for Body {
+    fn from(text: &str) -> Self {
+        Body::Text(text.to_owned())
+    }
+}
+
+impl From<Vec<u8>> for Body {
+    fn from(bytes: Vec<u8>) -> Self {
+        Body::Bytes(bytes)
+    }
+}
+
+/// An error that occurred while making a request.
+#[derive(Debug, Error)]
+pub enum FetchError {
+    /// The request couldn't be built from the given [`Request`].
+    #[error("invalid request: {0}")]
+    InvalidRequest(String),
+    /// The request failed, for example because of a network error.
+    #[error("request failed: {0}")]
+    Request(String),
+    /// The request was aborted.
+    #[error("request was aborted")]
+    Aborted,
+    /// The response couldn't be read.
+    #[error("invalid response: {0}")]
+    InvalidResponse(String),
+    /// The response body couldn't be decoded.
+    #[error(transparent)]
+    Format(#[from] FormatError),
+}
+
+impl FetchError {
+    fn from_js(err: JsValue) -> Self {
+        let name = js_sys::Reflect::get(&err, &JsValue::from_str("name"))
+            .ok()
+            .and_then(|name| name.as_string());
+        if name.as_deref() == Some("AbortError") {
+            return FetchError::Aborted;
+        }
+
+        let message = match err.dyn_ref::<js_sys::Error>() {
+            Some(err) => String::from(err.message()),
+            None => err.as_string().unwrap_or_else(|| format!("{:?}", err)),
+        };
+        FetchError::Request(message)
+    }
+}
+
+/// The future returned by a [`Transport`].
+pub type TransportFuture = Pin<Box<dyn Future<Output = Result<Response<Vec<u8>>, FetchError>>>>;
+
+/// Sends requests on behalf of a [`FetchClient`].
+///
+/// This is implemented for closures taking a `Request<Body>` and returning a response, which
+/// makes it easy to mock requests in tests.
+pub trait Transport {
+    /// Sends the request. Dropping the returned future must cancel the request.
+    fn send(&self, request: Request<Body>) -> TransportFuture;
+}
+
+impl<F> Transport for F
+where
+    F: Fn(Request<Body>) -> Result<Response<Vec<u8>>, FetchError>,
+{
+    fn send(&self, request: Request<Body>) -> TransportFuture {
+        Box::pin(std::future::ready(self(request)))
+    }
+}
+
+/// The [`Transport`] which sends requests using the browser's `fetch` function.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct BrowserTransport;
+
+impl Transport for BrowserTransport {
+    fn send(&self, request: Request<Body>) -> TransportFuture {
+        Box::pin(browser_fetch(request))
+    }
+}
+
+/// Aborts the request it guards when dropped, unless it was disarmed.
+struct AbortGuard {
+    controller: AbortController,
+    armed: bool,
+}
+
+impl Drop for AbortGuard {
+    fn drop(&mut self) {
+        if self.armed {
+            self.controller.abort();
+        }
+    }
+}
+
+async fn browser_fetch(request: Request<Body>) -> Result<Response<Vec<u8>>, FetchError> {
+    let controller = AbortController::new().map_err(FetchError::from_js)?;
+    let mut guard = AbortGuard {
+        controller,
+        armed: true,
+    };
+
+    let request = build_request(request, &guard.controller.signal())?;
+    let response = JsFuture::from(window().fetch_with_request(&request))
+        .await
+        .map_err(FetchError::from_js)?;
+    let response: web_sys::Response = response
+        .dyn_into()
+        .map_err(|_| FetchError::InvalidResponse("fetch didn't return a Response".into()))?;
+
+    let mut builder = Response::builder().status(response.status());
+    let headers = js_sys::try_iter(response.headers().as_ref()).map_err(FetchError::from_js)?;
+    for entry in headers.into_iter().flatten() {
+        let entry: js_sys::Array = entry.map_err(FetchError::from_js)?.unchecked_into();
+        if let (Some(name), Some(value)) = (entry.get(0).as_string(), entry.get(1).as_string()) {
+            builder = builder.header(name.as_str(), value.as_str());
+        }
+    }
+
+    let buffer = response.array_buffer().map_err(FetchError::from_js)?;
+    let buffer = JsFuture::from(buffer).await.map_err(FetchError::from_js)?;
+    guard.armed = false;
+
+    let body = js_sys::Uint8Array::new(&buffer).to_vec();
+    builder
+        .body(body)
+        .map_err(|err| FetchError::InvalidResponse(err.to_string()))
+}
+
+fn build_request(
+    request: Request<Body>,
+    signal: &AbortSignal,
+) -> Result<web_sys::Request, FetchError> {
+    let (parts, body) = request.into_parts();
+
+    let headers = Headers::new().map_err(FetchError::from_js)?;
+    for (name, value) in parts.headers.iter() {
+        let value = value
+            .to_str()
+            .map_err(|err| FetchError::InvalidRequest(err.to_string()))?;
+        headers
+            .append(name.as_str(), value)
+            .map_err(FetchError::from_js)?;
+    }
+
+    let body = match body {
+        Body::Empty => None,
+        Body::Text(text) => Some(JsValue::from_str(&text)),
+        Body::Bytes(data) | Body::Encoded { data, .. } => {
+            Some(js_sys::Uint8Array::from(data.as_slice()).into())
+        }
+    };
+
+    let mut init = RequestInit::new();
+    init.method(parts.method.as_str())
+        .headers(&headers)
+        .body(body.as_ref())
+        .signal(Some(signal));
+
+    web_sys::Request::new_with_str_and_init(&parts.uri.to_string(), &init)
+        .map_err(FetchError::from_js)
+}
+
+/// A pending request. Resolves to the response once it has been received and decoded.
+///
+/// Dropping the task aborts the request.
+pub struct FetchTask<T> {
+    future: Pin<Box<dyn Future<Output = Result<Response<T>, FetchError>>>>,
+}
+
+impl<T> fmt::Debug for FetchTask<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("FetchTask<_>")
+    }
+}
+
+impl<T> Future for FetchTask<T> {
+    type Output = Result<Response<T>, FetchError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.future.as_mut().poll(cx)
+    }
+}
+
+/// Sends requests through a [`Transport`].
+///
+/// The default client uses the [`BrowserTransport`]. A client can be provided to function
+/// components through a [`ContextProvider`](crate::context::ContextProvider), which
+/// [`use_fetch`](crate::functional::use_fetch) picks up instead of the default one.
+#[derive(Clone)]
+pub struct FetchClient {
+    transport: Rc<dyn Transport>,
+}
+
+impl fmt::Debug for FetchClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("FetchClient<_>")
+    }
+}
+
+impl Default for FetchClient {
+    fn default() -> Self {
+        Self::new(BrowserTransport)
+    }
+}
+
+impl PartialEq for FetchClient {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.transport, &other.transport)
+    }
+}
+
+impl FetchClient {
+    /// Creates a client which sends requests through `transport`.
+    pub fn new(transport: impl Transport + 'static) -> Self {
+        Self {
+            transport: Rc::new(transport),
+        }
+    }
+
+    /// Sends a request and returns the response with the raw body.
+    pub fn send<B: Into<Body>>(&self, request: Request<B>) -> FetchTask<Vec<u8>> {
+        FetchTask {
+            future: self.transport.send(into_request(request)),
+        }
+    }
+
+    /// Sends a request and decodes the response body as JSON.
+    pub fn fetch<B, T>(&self, request: Request<B>) -> FetchTask<T>
+    where
+        B: Into<Body>,
+        T: DeserializeOwned + 'static,
+    {
+        self.fetch_with_format::<Json, B, T>(request)
+    }
+
+    /// Sends a request and decodes the response body with the format `F`.
+    ///
+    /// The body is decoded regardless of the response status.
+    pub fn fetch_with_format<F, B, T>(&self, request: Request<B>) -> FetchTask<T>
+    where
+        F: Format + 'static,
+        B: Into<Body>,
+        T: DeserializeOwned + 'static,
+    {
+        let response = self.transport.send(into_request(request));
+        FetchTask {
+            future: Box::pin(async move {
+                let (parts, body) = response.await?.into_parts();
+                let body = F::decode(&body)?;
+                Ok(Response::from_parts(parts, body))
+            }),
+        }
+    }
+}
+
+/// Converts the body of `request` and adds the content type of an encoded body.
+fn into_request<B: Into<Body>>(request: Request<B>) -> Request<Body> {
+    let mut request = request.map(Into::into);
+    if let Some(content_type) = request.body().content_type() {
+        request
+            .headers_mut()
+            .entry(http::header::CONTENT_TYPE)
+            .or_insert(http::HeaderValue::from_static(content_type));
+    }
+    request
+}
+
+/// Sends a request with the default client and decodes the response body as JSON.
+pub fn fetch<B, T>(request: Request<B>) -> FetchTask<T>
+where
+    B: Into<Body>,
+    T: DeserializeOwned + 'static,
+{
+    FetchClient::default().fetch(request)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use std::task::{RawWaker, RawWakerVTable, Waker};
+
+    fn noop_waker() -> Waker {
+        fn clone(_: *const ()) -> RawWaker {
+            RawWaker::new(std::ptr::null(), &VTABLE)
+        }
+        fn noop(_: *const ()) {}
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+
+        unsafe { Waker::from_raw(RawWaker::new(std::ptr::null(), &VTABLE)) }
+    }
+
+    fn poll_ready<T>(mut task: FetchTask<T>) -> Result<Response<T>, FetchError> {
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        match Pin::new(&mut task).poll(&mut cx) {
+            Poll::Ready(result) => result,
+            Poll::Pending => panic!("mock transport should resolve immediately"),
+        }
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct User {
+        name: String,
+    }
+
+    fn mock_client() -> FetchClient {
+        FetchClient::new(|request: Request<Body>| {
+            let status = match request.uri().path() {
+                "/users/1" => StatusCode::OK,
+                _ => StatusCode::NOT_FOUND,
+            };
+            let body = match request.body() {
+                Body::Text(text) => text.as_bytes().to_vec(),
+                Body::Encoded { data, .. } => data.clone(),
+                _ => br#"{"name":"Minka"}"#.to_vec(),
+            };
+            Ok(Response::builder().status(status).body(body).unwrap())
+        })
+    }
+
+    #[test]
+    fn decodes_json_response() {
+        let request = Request::get("/users/1").body(()).unwrap();
+        let response = poll_ready(mock_client().fetch::<_, User>(request)).unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.into_body(),
+            User {
+                name: "Minka".into()
+            }
+        );
+    }
+
+    #[test]
+    fn keeps_response_status() {
+        let request = Request::get("/users/2").body(()).unwrap();
+        let response = poll_ready(mock_client().send(request)).unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn reports_decode_errors() {
+        let request = Request::post("/users/1").body("not json").unwrap();
+        let result = poll_ready(mock_client().fetch::<_, User>(request));
+
+        assert!(matches!(
+            result,
+            Err(FetchError::Format(FormatError::Decode(_)))
+        ));
+    }
+
+    #[test]
+    fn sends_content_type_of_encoded_body() {
+        let client = FetchClient::new(|request: Request<Body>| {
+            let content_type = request.headers()[http::header::CONTENT_TYPE].as_bytes();
+            Ok(Response::new(content_type.to_vec()))
+        });
+
+        let body = Body::encode::<Json, _>(&"Minka").unwrap();
+        let request = Request::post("/users").body(body.clone()).unwrap();
+        let response = poll_ready(client.send(request)).unwrap();
+        assert_eq!(response.body(), b"application/json");
+
+        let request = Request::post("/users")
+            .header(http::header::CONTENT_TYPE, "text/plain")
+            .body(body)
+            .unwrap();
+        let response = poll_ready(client.send(request)).unwrap();
+        assert_eq!(response.body(), b"text/plain");
+    }
+}
@@ -0,0 +1,235 @@
+//! This module contains the serialization formats used to encode and decode data exchanged
+//! with the outside world, for example the bodies of [`fetch`](crate::fetch) requests.
+//!
+//! [`Json`] is always available. The binary formats are enabled with cargo features:
+//!
+//! | Format      | Feature             |
+//! |-------------|---------------------|
+//! | [`Bincode`] | `bincode` / `agent` |
+//! | [`MsgPack`] | `msgpack`           |
+//! | [`Cbor`]    | `cbor`              |
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use thiserror::Error;
+
+/// An error that occurred while encoding or decoding data.
+#[derive(Debug, Error)]
+pub enum FormatError {
+    /// The value couldn't be encoded.
+    #[error("failed to encode data: {0}")]
+    Encode(String),
+    /// The data couldn't be decoded.
+    #[error("failed to decode data: {0}")]
+    Decode(String),
+}
+
+/// A serialization format.
+///
+/// Formats are zero-sized marker types which are passed as a type parameter to the APIs that
+/// need to encode or decode data.
+pub trait Format {
+    /// The MIME type of data encoded with this format.
+    const CONTENT_TYPE: &'static str;
+
+    /// Whether data encoded with this format is valid UTF-8 text.
+    ///
+    /// Text formats are, for example, sent as text frames over a
+    /// [`WebSocket`](crate::websocket).
+    const IS_TEXT: bool;
+
+    /// Encodes `value` into bytes.
+    fn encode<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, FormatError>;
+
+    /// Decodes a value from bytes.
+    fn decode<T: DeserializeOwned>(data: &[u8]) -> Result<T, FormatError>;
+}
+
+/// The JSON format, backed by `serde_json`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Json;
+
+impl Format for Json {
+    const CONTENT_TYPE: &'static str = "application/json";
+    const IS_TEXT: bool = true;
+
+    fn encode<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, FormatError> {
+        serde_json::to_vec(value).map_err(|err| FormatError::Encode(err.to_string()))
+    }
+
+    fn decode<T: DeserializeOwned>(data: &[u8]) -> Result<T, FormatError> {
+        serde_json::from_slice(data).map_err(|err| FormatError::Decode(err.to_string()))
+    }
+}
+
+/// The [bincode](https://github.com/bincode-org/bincode) format.
+///
+/// Requires the `bincode` feature, which is enabled by the default `agent` feature.
+#[cfg(feature = "bincode")]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Bincode;
+
+#[cfg(feature = "bincode")]
+impl Format for Bincode {
+    const CONTENT_TYPE: &'static str = "application/octet-stream";
+    const IS_TEXT: bool = false;
+
+    fn encode<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, FormatError> {
+        bincode::serialize(value).map_err(|err| FormatError::Encode(err.to_string()))
+    }
+
+    fn decode<T: DeserializeOwned>(data: &[u8]) -> Result<T, FormatError> {
+        bincode::deserialize(data).map_err(|err| FormatError::Decode(err.to_string()))
+    }
+}
+
+/// The [MessagePack](https://msgpack.org) format, backed by `rmp-serde`.
+///
+/// Structs are encoded as maps so that fields can be added without breaking older readers.
+///
+/// Requires the `msgpack` feature.
+#[cfg(feature = "msgpack")]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MsgPack;
+
+#[cfg(feature = "msgpack")]
+impl Format for MsgPack {
+    const CONTENT_TYPE: &'static str = "application/msgpack";
+    const IS_TEXT: bool = false;
+
+    fn encode<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, FormatError> {
+        rmp_serde::to_vec_named(value).map_err(|err| FormatError::Encode(err.to_string()))
+    }
+
+    fn decode<T: DeserializeOwned>(data: &[u8]) -> Result<T, FormatError> {
+        rmp_serde::from_read_ref(data).map_err(|err| FormatError::Decode(err.to_string()))
+    }
+}
+
+/// The [CBOR](https://cbor.io) format, backed by `serde_cbor`.
+///
+/// Requires the `cbor` feature.
+#[cfg(feature = "cbor")]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Cbor;
+
+#[cfg(feature = "cbor")]
+impl Format for Cbor {
+    const CONTENT_TYPE: &'static str = "application/cbor";
+    const IS_TEXT: bool = false;
+
+    fn encode<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, FormatError> {
+        let mut data = Vec::new();
+        let mut serializer = serde_cbor::Serializer::new(&mut data);
+        value
+            .serialize(&mut serializer)
+            .map_err(|err| FormatError::Encode(err.to_string()))?;
+        Ok(data)
+    }
+
+    fn decode<T: DeserializeOwned>(data: &[u8]) -> Result<T, FormatError> {
+        serde_cbor::from_slice(data).map_err(|err| FormatError::Decode(err.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use std::collections::HashMap;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Kind {
+        Unit,
+        Tuple(i32, String),
+        Struct { flag: bool },
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Data {
+        id: u64,
+        name: String,
+        ratio: f64,
+        tags: Vec<String>,
+        extra: Option<HashMap<String, i64>>,
+        kinds: Vec<Kind>,
+        bytes: Vec<u8>,
+    }
+
+    fn sample() -> Data {
+        let mut extra = HashMap::new();
+        extra.insert("answer".to_owned(), 42);
+        extra.insert("negative".to_owned(), -7);
+
+        Data {
+            id: u64::MAX,
+            name: "yew \u{1f980}".to_owned(),
+            ratio: 0.25,
+            tags: vec!["a".to_owned(), String::new()],
+            extra: Some(extra),
+            kinds: vec![
+                Kind::Unit,
+                Kind::Tuple(-1, "tuple".to_owned()),
+                Kind::Struct { flag: true },
+            ],
+            bytes: vec![0, 1, 254, 255],
+        }
+    }
+
+    fn round_trip<F: Format>() {
+        let data = sample();
+        let encoded = F::encode(&data).expect("failed to encode");
+        if F::IS_TEXT {
+            assert!(std::str::from_utf8(&encoded).is_ok());
+        }
+        let decoded: Data = F::decode(&encoded).expect("failed to decode");
+        assert_eq!(decoded, data);
+    }
+
+    fn unsized_round_trip<F: Format>() {
+        let tags: &[String] = &sample().tags;
+        let encoded = F::encode(tags).expect("failed to encode");
+        let decoded: Vec<String> = F::decode(&encoded).expect("failed to decode");
+        assert_eq!(decoded, tags);
+    }
+
+    fn decode_error<F: Format>() {
+        let encoded = F::encode(&sample()).unwrap();
+        let truncated = &encoded[..encoded.len() / 2];
+        assert!(matches!(
+            F::decode::<Data>(truncated),
+            Err(FormatError::Decode(_))
+        ));
+    }
+
+    #[test]
+    fn json_round_trip() {
+        round_trip::<Json>();
+        unsized_round_trip::<Json>();
+        decode_error::<Json>();
+    }
+
+    #[cfg(feature = "bincode")]
+    #[test]
+    fn bincode_round_trip() {
+        round_trip::<Bincode>();
+        unsized_round_trip::<Bincode>();
+        decode_error::<Bincode>();
+    }
+
+    #[cfg(feature = "msgpack")]
+    #[test]
+    fn msgpack_round_trip() {
+        round_trip::<MsgPack>();
+        unsized_round_trip::<MsgPack>();
+        decode_error::<MsgPack>();
+    }
+
+    #[cfg(feature = "cbor")]
+    #[test]
+    fn cbor_round_trip() {
+        round_trip::<Cbor>();
+        unsized_round_trip::<Cbor>();
+        decode_error::<Cbor>();
+    }
+}
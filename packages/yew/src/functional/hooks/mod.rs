@@ -1,14 +1,28 @@
 mod use_context;
 mod use_effect;
+mod use_fetch;
+mod use_future;
+mod use_interval;
+mod use_persisted_state;
+mod use_raf;
 mod use_reducer;
 mod use_ref;
 mod use_state;
+mod use_timeout;
+mod use_websocket;
 
 pub use use_context::*;
 pub use use_effect::*;
+pub use use_fetch::*;
+pub use use_future::*;
+pub use use_interval::*;
+pub use use_persisted_state::*;
+pub use use_raf::*;
 pub use use_reducer::*;
 pub use use_ref::*;
 pub use use_state::*;
+pub use use_timeout::*;
+pub use use_websocket::*;
 
 use crate::functional::{HookUpdater, CURRENT_HOOK};
 use std::cell::RefCell;
@@ -63,3 +63,73 @@ pub fn use_context<T: Clone + PartialEq + 'static>() -> Option<T> {
         },
     )
 }
+
+/// Hook for consuming a part of a context value in function components.
+///
+/// `selector` projects the context of type `T` onto the value the component actually needs.
+/// Unlike [`use_context`], the component only re-renders when the projected value changes,
+/// not whenever any part of the context changes.
+/// If there is no such context in scope, `None` is returned.
+///
+/// Note that the selector passed on the first render is used for the lifetime of the component.
+///
+/// # Example
+/// ```rust
+/// # use yew::prelude::*;
+/// # use std::rc::Rc;
+///
+/// # #[derive(Clone, Debug, PartialEq)]
+/// # struct AppState {
+/// #    user_name: String,
+/// #    notifications: Vec<String>,
+/// # }
+/// #[function_component(UserName)]
+/// pub fn user_name() -> Html {
+///     // Changes to `notifications` won't re-render this component.
+///     let user_name = use_context_selector(|state: &Rc<AppState>| state.user_name.clone())
+///         .expect("no ctx found");
+///
+///     html! { <span>{ user_name }</span> }
+/// }
+/// ```
+pub fn use_context_selector<T, S, F>(selector: F) -> Option<S>
+where
+    T: Clone + PartialEq + 'static,
+    S: Clone + PartialEq + 'static,
+    F: Fn(&T) -> S + 'static,
+{
+    struct UseContextSelectorState<T2: Clone + PartialEq + 'static, S2> {
+        initialized: bool,
+        context: Option<(S2, ContextHandle<T2>)>,
+    }
+
+    let scope = get_current_scope().expect(
+        "No current Scope. `use_context_selector` can only be called inside function components",
+    );
+
+    use_hook(
+        move || UseContextSelectorState {
+            initialized: false,
+            context: None,
+        },
+        |state: &mut UseContextSelectorState<T, S>, updater| {
+            if !state.initialized {
+                state.initialized = true;
+                let callback = move |selected: S| {
+                    updater.callback(|state: &mut UseContextSelectorState<T, S>| {
+                        if let Some(context) = &mut state.context {
+                            context.0 = selected;
+                        }
+                        true
+                    });
+                };
+                state.context = scope.context_selector::<T, S, F>(selector, callback.into());
+            }
+
+            Some(state.context.as_ref()?.0.clone())
+        },
+        |state| {
+            state.context = None;
+        },
+    )
+}
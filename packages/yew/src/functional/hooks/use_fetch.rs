@@ -0,0 +1,78 @@
+use crate::fetch::{Body, FetchClient, FetchError, Request, Response};
+use crate::format::{Format, Json};
+use crate::functional::{use_context, use_future, UseFutureState};
+use serde::de::DeserializeOwned;
+
+/// The state returned by [`use_fetch`].
+pub type UseFetchState<T> = UseFutureState<Result<Response<T>, FetchError>>;
+
+/// This hook sends a request and decodes the JSON response body.
+///
+/// The request is built by calling `request_fn` on the first render and every time the
+/// dependencies change. A request that is still in flight when the dependencies change or the
+/// component is unmounted is aborted.
+///
+/// Requests are sent through the [`FetchClient`] provided by the closest
+/// [`ContextProvider<FetchClient>`](crate::context::ContextProvider), or through the default
+/// client if there is none. Providing a client with a mock transport makes components using
+/// this hook testable without a server.
+///
+/// # Example
+/// ```rust
+/// # use yew::prelude::*;
+/// # use yew::fetch::Request;
+/// # use serde::Deserialize;
+/// #
+/// #[derive(Deserialize)]
+/// struct User {
+///     name: String,
+/// }
+///
+/// #[derive(Properties, Clone, PartialEq)]
+/// pub struct Props {
+///     user_id: u32,
+/// }
+///
+/// #[function_component(UserName)]
+/// fn user_name(props: &Props) -> Html {
+///     let user_id = props.user_id;
+///     let user = use_fetch::<User, _, _, _>(user_id, move || {
+///         Request::get(format!("/api/users/{}", user_id))
+///             .body(())
+///             .expect("valid request")
+///     });
+///
+///     match user.ready() {
+///         None => html! { "Loading..." },
+///         Some(Ok(response)) => html! { <b>{ response.body().name.clone() }</b> },
+///         Some(Err(err)) => html! { <i>{ err.to_string() }</i> },
+///     }
+/// }
+/// ```
+pub fn use_fetch<T, B, Dependents, F>(deps: Dependents, request_fn: F) -> UseFetchState<T>
+where
+    T: DeserializeOwned + 'static,
+    B: Into<Body>,
+    Dependents: PartialEq + 'static,
+    F: FnOnce() -> Request<B>,
+{
+    use_fetch_with_format::<Json, T, B, Dependents, F>(deps, request_fn)
+}
+
+/// This hook is similar to [`use_fetch`] but decodes the response body with the format `Fmt`.
+pub fn use_fetch_with_format<Fmt, T, B, Dependents, F>(
+    deps: Dependents,
+    request_fn: F,
+) -> UseFetchState<T>
+where
+    Fmt: Format + 'static,
+    T: DeserializeOwned + 'static,
+    B: Into<Body>,
+    Dependents: PartialEq + 'static,
+    F: FnOnce() -> Request<B>,
+{
+    let client = use_context::<FetchClient>().unwrap_or_default();
+    use_future(deps, move || {
+        client.fetch_with_format::<Fmt, B, T>(request_fn())
+    })
+}
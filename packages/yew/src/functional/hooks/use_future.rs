@@ -0,0 +1,131 @@
+use crate::functional::use_hook;
+use crate::task::{spawn_local, TaskHandle};
+use std::fmt;
+use std::future::Future;
+use std::rc::Rc;
+
+/// The state of a future driven by [`use_future`].
+pub enum UseFutureState<T> {
+    /// The future hasn't resolved yet.
+    Pending,
+    /// The future resolved to the contained value.
+    Ready(Rc<T>),
+}
+
+impl<T> UseFutureState<T> {
+    /// Returns `true` if the future hasn't resolved yet.
+    pub fn is_pending(&self) -> bool {
+        matches!(self, Self::Pending)
+    }
+
+    /// Returns the resolved value, if any.
+    pub fn ready(&self) -> Option<&T> {
+        match self {
+            Self::Pending => None,
+            Self::Ready(value) => Some(value),
+        }
+    }
+}
+
+impl<T> Clone for UseFutureState<T> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Pending => Self::Pending,
+            Self::Ready(value) => Self::Ready(Rc::clone(value)),
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for UseFutureState<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pending => f.write_str("Pending"),
+            Self::Ready(value) => f.debug_tuple("Ready").field(value).finish(),
+        }
+    }
+}
+
+struct UseFuture<T, Dependents> {
+    state: UseFutureState<T>,
+    deps: Option<Dependents>,
+    task: Option<TaskHandle>,
+    generation: usize,
+}
+
+/// This hook is used to run a future and re-render the component once it resolves.
+///
+/// The future is created by calling `future_fn` on the first render and every time the
+/// dependencies change. A future that is still in flight when the dependencies change or the
+/// component is unmounted is aborted and its result is discarded.
+///
+/// # Example
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// # async fn fetch_user_name(id: u32) -> String { format!("user {}", id) }
+/// #
+/// #[derive(Properties, Clone, PartialEq)]
+/// pub struct Props {
+///     user_id: u32,
+/// }
+///
+/// #[function_component(UserName)]
+/// fn user_name(props: &Props) -> Html {
+///     let user_id = props.user_id;
+///     let name = use_future(user_id, move || fetch_user_name(user_id));
+///
+///     match name {
+///         UseFutureState::Pending => html! { "Loading..." },
+///         UseFutureState::Ready(name) => html! { <b>{ (*name).clone() }</b> },
+///     }
+/// }
+/// ```
+pub fn use_future<Dependents, F, Fut>(deps: Dependents, future_fn: F) -> UseFutureState<Fut::Output>
+where
+    Dependents: PartialEq + 'static,
+    F: FnOnce() -> Fut,
+    Fut: Future + 'static,
+    Fut::Output: 'static,
+{
+    use_hook(
+        move || UseFuture {
+            state: UseFutureState::Pending,
+            deps: None,
+            task: None,
+            generation: 0,
+        },
+        move |hook: &mut UseFuture<Fut::Output, Dependents>, updater| {
+            if hook.deps.as_ref() != Some(&deps) {
+                if let Some(task) = hook.task.take() {
+                    task.abort();
+                }
+
+                hook.deps = Some(deps);
+                hook.state = UseFutureState::Pending;
+                hook.generation += 1;
+
+                let generation = hook.generation;
+                let future = future_fn();
+                hook.task = Some(spawn_local(async move {
+                    let output = future.await;
+                    updater.callback(move |hook: &mut UseFuture<Fut::Output, Dependents>| {
+                        // Results of superseded futures are discarded
+                        if hook.generation != generation {
+                            return false;
+                        }
+                        hook.state = UseFutureState::Ready(Rc::new(output));
+                        hook.task = None;
+                        true
+                    });
+                }));
+            }
+
+            hook.state.clone()
+        },
+        |hook| {
+            if let Some(task) = hook.task.take() {
+                task.abort();
+            }
+        },
+    )
+}
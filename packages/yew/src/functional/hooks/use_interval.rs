@@ -0,0 +1,73 @@
+use crate::functional::use_hook;
+use gloo::timers::callback::Interval;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+struct UseInterval {
+    callback: Rc<RefCell<Rc<dyn Fn()>>>,
+    millis: Option<u32>,
+    interval: Option<Interval>,
+}
+
+/// This hook calls `callback` every `millis` milliseconds while the component is mounted.
+///
+/// Passing `None` as the duration pauses the interval. Changing the duration restarts the
+/// interval, while a new callback passed on a later render is picked up without resetting it.
+/// The interval is cleared when the component is unmounted.
+///
+/// # Example
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// #[function_component(Ticker)]
+/// fn ticker() -> Html {
+///     let ticks = use_state(|| 0);
+///     let running = use_state(|| true);
+///
+///     {
+///         let ticks = ticks.clone();
+///         use_interval(move || ticks.set(*ticks + 1), running.then(|| 1000));
+///     }
+///
+///     let onclick = {
+///         let running = running.clone();
+///         Callback::from(move |_| running.set(!*running))
+///     };
+///
+///     html! {
+///         <button {onclick}>{ format!("Ticks: {}", *ticks) }</button>
+///     }
+/// }
+/// ```
+pub fn use_interval<F>(callback: F, millis: impl Into<Option<u32>>)
+where
+    F: Fn() + 'static,
+{
+    let millis = millis.into();
+    use_hook(
+        || UseInterval {
+            callback: Rc::new(RefCell::new(Rc::new(|| ()))),
+            millis: None,
+            interval: None,
+        },
+        move |hook: &mut UseInterval, _| {
+            *hook.callback.borrow_mut() = Rc::new(callback);
+
+            if hook.millis != millis {
+                hook.millis = millis;
+                // Dropping the previous interval clears it
+                hook.interval = millis.map(|millis| {
+                    let callback = Rc::clone(&hook.callback);
+                    Interval::new(millis, move || {
+                        // Clone the callback out so a re-render triggered by it can replace it
+                        let callback = Rc::clone(&callback.borrow());
+                        callback();
+                    })
+                });
+            }
+        },
+        |hook| {
+            hook.interval = None;
+        },
+    )
+}
@@ -0,0 +1,193 @@
+use crate::format::{Format, Json};
+use crate::functional::{use_hook, HookUpdater};
+use crate::storage::{Storage, StorageArea, StorageError};
+use crate::utils::window;
+use gloo::events::EventListener;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fmt;
+use std::ops::Deref;
+use std::rc::Rc;
+use wasm_bindgen::JsCast;
+use web_sys::StorageEvent;
+
+type Source = (StorageArea, String);
+
+struct UsePersistedState<T> {
+    /// The storage area and namespaced key which the value was loaded from, `None` before the
+    /// first render.
+    source: Option<Source>,
+    current: Option<Rc<T>>,
+    listener: Option<EventListener>,
+}
+
+/// This hook is similar to [`use_state`](super::use_state()) but persists the state in
+/// `localStorage` as JSON.
+///
+/// The value stored under `key` is loaded on mount, falling back to `init` if there is none or
+/// it can't be decoded. It's loaded again whenever `key` or the storage changes. Every value passed to
+/// [`UsePersistedStateHandle::set`] is written back. Changes made to the same key by other tabs
+/// are picked up and re-render the component.
+///
+/// # Example
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// #[function_component(Counter)]
+/// fn counter() -> Html {
+///     let counter = use_persisted_state("counter", || 0);
+///     let onclick = {
+///         let counter = counter.clone();
+///         Callback::from(move |_| {
+///             if let Err(err) = counter.set(*counter + 1) {
+///                 yew::utils::window().alert_with_message(&err.to_string()).ok();
+///             }
+///         })
+///     };
+///
+///     html! {
+///         <button {onclick}>{ format!("Clicked {} times", *counter) }</button>
+///     }
+/// }
+/// ```
+pub fn use_persisted_state<T, F>(key: &str, init: F) -> UsePersistedStateHandle<T>
+where
+    T: Serialize + DeserializeOwned + 'static,
+    F: FnOnce() -> T,
+{
+    use_persisted_state_with::<Json, T, F>(Storage::local(), key, init)
+}
+
+/// This hook is similar to [`use_persisted_state`] but persists the state in the given
+/// [`Storage`], which determines the storage area, key namespace and format.
+pub fn use_persisted_state_with<Fmt, T, F>(
+    storage: Storage<Fmt>,
+    key: &str,
+    init: F,
+) -> UsePersistedStateHandle<T>
+where
+    Fmt: Format + 'static,
+    T: Serialize + DeserializeOwned + 'static,
+    F: FnOnce() -> T,
+{
+    let key = key.to_owned();
+    use_hook(
+        || UsePersistedState {
+            source: None,
+            current: None,
+            listener: None,
+        },
+        move |hook: &mut UsePersistedState<T>, updater| {
+            let source = (storage.area(), storage.storage_key(&key));
+            if hook.source.as_ref() != Some(&source) {
+                let value = storage.get(&key).ok().flatten().unwrap_or_else(init);
+                hook.current = Some(Rc::new(value));
+                hook.listener = Some(storage_listener::<Fmt, T>(
+                    storage.clone(),
+                    source.clone(),
+                    updater.clone(),
+                ));
+                hook.source = Some(source.clone());
+            }
+
+            let setter: Rc<dyn Fn(T) -> Result<(), StorageError>> = Rc::new(move |value: T| {
+                let result = storage.set(&key, &value);
+                let source = source.clone();
+                updater.callback(move |hook: &mut UsePersistedState<T>| {
+                    // The value belongs to the old key or storage if it has changed since
+                    if hook.source.as_ref() != Some(&source) {
+                        return false;
+                    }
+                    hook.current = Some(Rc::new(value));
+                    true
+                });
+                result
+            });
+
+            UsePersistedStateHandle {
+                value: Rc::clone(hook.current.as_ref().expect("the value is loaded above")),
+                setter,
+            }
+        },
+        |hook| {
+            hook.listener = None;
+        },
+    )
+}
+
+/// Listens for changes made to `source` by other tabs.
+fn storage_listener<Fmt, T>(
+    storage: Storage<Fmt>,
+    source: Source,
+    updater: HookUpdater,
+) -> EventListener
+where
+    Fmt: Format + 'static,
+    T: DeserializeOwned + 'static,
+{
+    EventListener::new(&window(), "storage", move |event| {
+        let event = match event.dyn_ref::<StorageEvent>() {
+            Some(event) => event,
+            None => return,
+        };
+        let (area, storage_key) = &source;
+        if event.key().as_ref() != Some(storage_key) || event.storage_area() != area.get().ok() {
+            return;
+        }
+        // Removed keys keep the current value
+        let value = event
+            .new_value()
+            .and_then(|raw| storage.decode::<T>(&raw).ok());
+        if let Some(value) = value {
+            let source = source.clone();
+            updater.callback(move |hook: &mut UsePersistedState<T>| {
+                if hook.source.as_ref() != Some(&source) {
+                    return false;
+                }
+                hook.current = Some(Rc::new(value));
+                true
+            });
+        }
+    })
+}
+
+/// State handle for the [`use_persisted_state`] hook.
+pub struct UsePersistedStateHandle<T> {
+    value: Rc<T>,
+    setter: Rc<dyn Fn(T) -> Result<(), StorageError>>,
+}
+
+impl<T> UsePersistedStateHandle<T> {
+    /// Updates the value and writes it to storage.
+    ///
+    /// The value is updated even if it couldn't be written, for example because the storage
+    /// quota was exceeded. The error is returned in that case.
+    pub fn set(&self, value: T) -> Result<(), StorageError> {
+        (self.setter)(value)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for UsePersistedStateHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UsePersistedStateHandle")
+            .field("value", &format!("{:?}", self.value))
+            .finish()
+    }
+}
+
+impl<T> Deref for UsePersistedStateHandle<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T> Clone for UsePersistedStateHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            value: Rc::clone(&self.value),
+            setter: Rc::clone(&self.setter),
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use crate::functional::use_hook;
+use gloo::render::{request_animation_frame, AnimationFrame};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+type FrameCallback = Rc<RefCell<Rc<dyn Fn(f64)>>>;
+type FrameSlot = Rc<RefCell<Option<AnimationFrame>>>;
+
+struct UseRaf {
+    callback: FrameCallback,
+    frame: FrameSlot,
+}
+
+/// Requests the next animation frame and keeps doing so after every frame.
+fn request_frame(callback: FrameCallback, frame: FrameSlot) {
+    let next_frame = {
+        let frame = Rc::clone(&frame);
+        request_animation_frame(move |timestamp| {
+            // Clone the callback out so a re-render triggered by it can replace it
+            let cb = Rc::clone(&callback.borrow());
+            cb(timestamp);
+            // The callback may have stopped the loop
+            if frame.borrow().is_some() {
+                request_frame(callback, frame);
+            }
+        })
+    };
+    *frame.borrow_mut() = Some(next_frame);
+}
+
+/// This hook calls `callback` on every animation frame while `running` is `true`.
+///
+/// The callback receives the frame's timestamp in milliseconds, as passed to
+/// [requestAnimationFrame](https://developer.mozilla.org/en-US/docs/Web/API/window/requestAnimationFrame).
+/// A new callback passed on a later render is used from the next frame on.
+/// The loop is stopped when the component is unmounted.
+///
+/// # Example
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// #[function_component(Clock)]
+/// fn clock() -> Html {
+///     let elapsed = use_state(|| 0.0);
+///
+///     {
+///         let elapsed = elapsed.clone();
+///         use_raf(move |timestamp| elapsed.set(timestamp), true);
+///     }
+///
+///     html! {
+///         <span>{ format!("{:.0} ms", *elapsed) }</span>
+///     }
+/// }
+/// ```
+pub fn use_raf<F>(callback: F, running: bool)
+where
+    F: Fn(f64) + 'static,
+{
+    use_hook(
+        || UseRaf {
+            callback: Rc::new(RefCell::new(Rc::new(|_| ()))),
+            frame: Rc::default(),
+        },
+        move |hook: &mut UseRaf, _| {
+            *hook.callback.borrow_mut() = Rc::new(callback);
+
+            let is_running = hook.frame.borrow().is_some();
+            if running && !is_running {
+                request_frame(Rc::clone(&hook.callback), Rc::clone(&hook.frame));
+            } else if !running && is_running {
+                // Dropping the pending frame cancels it
+                hook.frame.borrow_mut().take();
+            }
+        },
+        |hook| {
+            hook.frame.borrow_mut().take();
+        },
+    )
+}
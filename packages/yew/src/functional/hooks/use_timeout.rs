@@ -0,0 +1,69 @@
+use crate::functional::use_hook;
+use gloo::timers::callback::Timeout;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+struct UseTimeout {
+    callback: Rc<RefCell<Rc<dyn Fn()>>>,
+    millis: Option<u32>,
+    timeout: Option<Timeout>,
+}
+
+/// This hook calls `callback` once, `millis` milliseconds after the component is mounted.
+///
+/// Passing `None` as the duration cancels a pending timeout. Changing the duration starts a new
+/// timeout, while a new callback passed on a later render is picked up without resetting it.
+/// A pending timeout is cancelled when the component is unmounted.
+///
+/// # Example
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// #[function_component(Toast)]
+/// fn toast() -> Html {
+///     let visible = use_state(|| true);
+///
+///     {
+///         let visible = visible.clone();
+///         use_timeout(move || visible.set(false), 3000);
+///     }
+///
+///     if *visible {
+///         html! { <div class="toast">{ "Saved!" }</div> }
+///     } else {
+///         html! {}
+///     }
+/// }
+/// ```
+pub fn use_timeout<F>(callback: F, millis: impl Into<Option<u32>>)
+where
+    F: Fn() + 'static,
+{
+    let millis = millis.into();
+    use_hook(
+        || UseTimeout {
+            callback: Rc::new(RefCell::new(Rc::new(|| ()))),
+            millis: None,
+            timeout: None,
+        },
+        move |hook: &mut UseTimeout, _| {
+            *hook.callback.borrow_mut() = Rc::new(callback);
+
+            if hook.millis != millis {
+                hook.millis = millis;
+                // Dropping the previous timeout cancels it
+                hook.timeout = millis.map(|millis| {
+                    let callback = Rc::clone(&hook.callback);
+                    Timeout::new(millis, move || {
+                        // Clone the callback out so a re-render triggered by it can replace it
+                        let callback = Rc::clone(&callback.borrow());
+                        callback();
+                    })
+                });
+            }
+        },
+        |hook| {
+            hook.timeout = None;
+        },
+    )
+}
@@ -0,0 +1,166 @@
+use crate::callback::Callback;
+use crate::format::{Format, FormatError, Json};
+use crate::functional::use_hook;
+use crate::websocket::{ReconnectPolicy, WebSocketStatus, WebSocketTask};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fmt;
+use std::rc::Rc;
+
+struct UseWebSocket<In, Out, F> {
+    url: Option<String>,
+    task: Option<Rc<WebSocketTask<In, Out, F>>>,
+    status: WebSocketStatus,
+    last_message: Option<Rc<Result<In, FormatError>>>,
+}
+
+/// Handle for the [`use_websocket`] hook.
+pub struct UseWebSocketHandle<In, Out, F = Json> {
+    task: Rc<WebSocketTask<In, Out, F>>,
+    status: WebSocketStatus,
+    last_message: Option<Rc<Result<In, FormatError>>>,
+}
+
+impl<In, Out, F> UseWebSocketHandle<In, Out, F>
+where
+    In: DeserializeOwned + 'static,
+    Out: Serialize,
+    F: Format + 'static,
+{
+    /// Sends a message. See [`WebSocketTask::send`].
+    pub fn send(&self, message: &Out) -> Result<(), FormatError> {
+        self.task.send(message)
+    }
+}
+
+impl<In, Out, F> UseWebSocketHandle<In, Out, F> {
+    /// The status of the connection when the component was rendered.
+    pub fn status(&self) -> WebSocketStatus {
+        self.status
+    }
+
+    /// The last message received, if any.
+    pub fn last_message(&self) -> Option<&Result<In, FormatError>> {
+        self.last_message.as_deref()
+    }
+}
+
+impl<In, Out, F> Clone for UseWebSocketHandle<In, Out, F> {
+    fn clone(&self) -> Self {
+        Self {
+            task: Rc::clone(&self.task),
+            status: self.status,
+            last_message: self.last_message.clone(),
+        }
+    }
+}
+
+impl<In, Out, F> fmt::Debug for UseWebSocketHandle<In, Out, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UseWebSocketHandle")
+            .field("status", &self.status)
+            .finish()
+    }
+}
+
+/// This hook connects to a WebSocket and re-renders the component whenever a message is
+/// received or the status of the connection changes.
+///
+/// Messages are encoded as JSON. The connection is re-established with the default
+/// [`ReconnectPolicy`] when it's lost. It's closed when the component is unmounted and
+/// re-opened when `url` changes.
+///
+/// # Example
+/// ```rust
+/// # use yew::prelude::*;
+/// # use yew::websocket::WebSocketStatus;
+/// #
+/// #[function_component(Chat)]
+/// fn chat() -> Html {
+///     let socket = use_websocket::<String, String>("wss://chat.example.com");
+///
+///     let onclick = {
+///         let socket = socket.clone();
+///         Callback::from(move |_| socket.send(&"Hello!".to_string()).unwrap())
+///     };
+///
+///     html! {
+///         <div>
+///             <button {onclick} disabled={socket.status() != WebSocketStatus::Open}>
+///                 { "Say hello" }
+///             </button>
+///             <p>{ socket.last_message().and_then(|message| message.as_ref().ok()).cloned() }</p>
+///         </div>
+///     }
+/// }
+/// ```
+pub fn use_websocket<In, Out>(url: &str) -> UseWebSocketHandle<In, Out>
+where
+    In: DeserializeOwned + 'static,
+    Out: Serialize + 'static,
+{
+    use_websocket_with::<In, Out, Json>(url, ReconnectPolicy::default())
+}
+
+/// This hook is similar to [`use_websocket`] but encodes messages with the format `F` and
+/// reconnects according to `reconnect`.
+pub fn use_websocket_with<In, Out, F>(
+    url: &str,
+    reconnect: ReconnectPolicy,
+) -> UseWebSocketHandle<In, Out, F>
+where
+    In: DeserializeOwned + 'static,
+    Out: Serialize + 'static,
+    F: Format + 'static,
+{
+    let url = url.to_owned();
+    use_hook(
+        || UseWebSocket {
+            url: None,
+            task: None,
+            status: WebSocketStatus::Connecting,
+            last_message: None,
+        },
+        move |hook: &mut UseWebSocket<In, Out, F>, updater| {
+            if hook.url.as_ref() != Some(&url) {
+                let on_message = {
+                    let updater = updater.clone();
+                    Callback::from(move |message: Result<In, FormatError>| {
+                        updater.callback(move |hook: &mut UseWebSocket<In, Out, F>| {
+                            hook.last_message = Some(Rc::new(message));
+                            true
+                        });
+                    })
+                };
+                let on_status = Callback::from(move |status: WebSocketStatus| {
+                    updater.callback(move |hook: &mut UseWebSocket<In, Out, F>| {
+                        let changed = hook.status != status;
+                        hook.status = status;
+                        changed
+                    });
+                });
+
+                if let Some(task) = hook.task.take() {
+                    task.close();
+                }
+                hook.status = WebSocketStatus::Connecting;
+                hook.last_message = None;
+                hook.task = Some(Rc::new(WebSocketTask::connect_with_reconnect(
+                    &url, on_message, on_status, reconnect,
+                )));
+                hook.url = Some(url);
+            }
+
+            UseWebSocketHandle {
+                task: Rc::clone(hook.task.as_ref().expect("socket is connected")),
+                status: hook.status,
+                last_message: hook.last_message.clone(),
+            }
+        },
+        |hook| {
+            if let Some(task) = hook.task.take() {
+                task.close();
+            }
+        },
+    )
+}
@@ -278,4 +278,28 @@ mod tests {
         assert!(subject.contains("foo"));
         assert!(subject.contains("bar"));
     }
+
+    #[test]
+    fn macro_adds_conditional_classes() {
+        extern crate self as yew;
+
+        let active = true;
+        let disabled = false;
+        let subject = crate::classes!(
+            "button",
+            "active" => active,
+            "disabled" => disabled,
+            { "primary": !disabled, "small": disabled },
+            Some("extra") => active,
+        );
+        assert_eq!(subject.to_string(), "button active primary extra");
+    }
+
+    #[test]
+    fn macro_deduplicates_literal_classes() {
+        extern crate self as yew;
+
+        let subject = crate::classes!("foo", "bar", "foo", "foo" => true);
+        assert_eq!(subject.to_string(), "foo bar");
+    }
 }
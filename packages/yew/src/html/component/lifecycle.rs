@@ -45,6 +45,13 @@ impl<COMP: Component> ComponentState<COMP> {
         }
     }
 
+    /// Updates the parent element of the component and of the components rendered directly
+    /// into it.
+    pub(crate) fn set_parent(&mut self, parent: Element) {
+        self.root_node.set_parent(&parent);
+        self.parent = parent;
+    }
+
     fn drain_pending_updates(&mut self, state: &Shared<Option<ComponentState<COMP>>>) {
         if !self.pending_updates.is_empty() {
             scheduler::push_component_updates(self.pending_updates.drain(..).map(|update| {
@@ -96,6 +103,8 @@ pub(crate) enum UpdateEvent<COMP: Component> {
     MessageBatch(Vec<COMP::Message>),
     /// Wraps properties, node ref, and next sibling for a component.
     Properties(Rc<COMP::Properties>, NodeRef, NodeRef),
+    /// Moves the rendered nodes of a component into a new parent element.
+    Reparent(Element),
 }
 
 pub(crate) struct ComponentRunnable<COMP: Component> {
@@ -149,6 +158,11 @@ impl<COMP: Component> Runnable for ComponentRunnable<COMP> {
                                 false
                             }
                         }
+                        UpdateEvent::Reparent(parent) => {
+                            state.root_node.move_before(&parent, &None);
+                            state.set_parent(parent);
+                            false
+                        }
                     };
 
                     if should_render {
@@ -1,5 +1,9 @@
 //! Component properties module
 
+use crate::html::IntoPropValue;
+use crate::virtual_dom::{AttrValue, Listener, Spread};
+use std::marker::PhantomData;
+use std::rc::Rc;
 pub use yew_macro::Properties;
 
 /// Trait for building properties for a component
@@ -28,3 +32,169 @@ impl EmptyBuilder {
     /// Build empty properties
     pub fn build(self) {}
 }
+
+/// Passes the properties builder through once `html!` has checked that every prop it sets is a
+/// field of the properties.
+///
+/// `fields` is never called. If it names a field which doesn't exist the compiler reports the
+/// unknown field together with the declared ones, and since the return type then can't be
+/// resolved, the methods which are missing from the builder aren't reported a second time.
+#[doc(hidden)]
+pub fn __check_props<P, B, T>(
+    builder: B,
+    _fields: impl FnOnce(P) -> T,
+) -> <T as CheckedProps<B>>::Builder {
+    builder
+}
+
+#[doc(hidden)]
+pub trait CheckedProps<B> {
+    type Builder;
+}
+
+impl<T, B> CheckedProps<B> for T {
+    type Builder = B;
+}
+
+/// Gives access to the `#[prop(rest)]` field of properties and their builder.
+///
+/// It's implemented for every derived `Properties` struct and its builder. `None` is returned if
+/// the struct doesn't have a rest field, in which case `html!` has already rejected the props that
+/// would end up there.
+#[doc(hidden)]
+pub trait RestPropsBuilder: Sized {
+    fn __yew_rest(&mut self) -> Option<&mut Spread>;
+
+    #[doc(hidden)]
+    fn __yew_rest_attribute(
+        mut self,
+        key: &'static str,
+        value: impl IntoPropValue<Option<AttrValue>>,
+    ) -> Self {
+        if let (Some(rest), Some(value)) = (self.__yew_rest(), value.into_prop_value()) {
+            rest.attributes.insert(key, value);
+        }
+        self
+    }
+
+    #[doc(hidden)]
+    fn __yew_rest_boolean(mut self, key: &'static str, value: bool) -> Self {
+        match self.__yew_rest() {
+            Some(rest) if value => {
+                rest.attributes.insert(key, AttrValue::Borrowed(key));
+            }
+            _ => {}
+        }
+        self
+    }
+
+    #[doc(hidden)]
+    fn __yew_rest_listener(mut self, listener: Option<Rc<dyn Listener>>) -> Self {
+        if let (Some(rest), Some(listener)) = (self.__yew_rest(), listener) {
+            rest.insert_listener(listener);
+        }
+        self
+    }
+}
+
+/// Collects the attributes and listeners which a component doesn't declare as props.
+///
+/// `html!` brings this trait into scope when it builds properties. Inherent builder methods take
+/// precedence over trait methods, so only props which aren't declared end up here. It's only
+/// implemented for builders so that its methods don't shadow the ones of the properties.
+#[doc(hidden)]
+pub trait RestProps: RestPropsBuilder {
+    yew_macro::__rest_props_methods!();
+}
+
+impl RestPropsBuilder for EmptyBuilder {
+    fn __yew_rest(&mut self) -> Option<&mut Spread> {
+        None
+    }
+}
+
+impl RestProps for EmptyBuilder {}
+
+yew_macro::__rest_fields!();
+
+/// Implemented for derived properties with a `#[prop(rest)]` field.
+#[doc(hidden)]
+pub trait HasRestField {}
+
+/// Picks the fields which `html!` checks attributes and listeners against when it sets them.
+///
+/// These are the fields of the properties, unless they have a `#[prop(rest)]` field which accepts
+/// every attribute and listener, in which case they're the fields of [`RestFields`]. Methods of
+/// [`RestFieldsProbe`] take precedence over the ones of [`PropsFieldsProbe`] since they don't need
+/// an extra reference, so properties which don't derive `Properties` are checked as they are.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct PropsProbe<P>(PhantomData<P>);
+
+#[doc(hidden)]
+pub fn __props_probe<P>() -> PropsProbe<P> {
+    PropsProbe(PhantomData)
+}
+
+#[doc(hidden)]
+pub trait RestFieldsProbe {
+    fn __yew_fields(&self) -> RestFields {
+        unreachable!("only used to check the props passed to `html!`")
+    }
+}
+
+impl<P: HasRestField> RestFieldsProbe for PropsProbe<P> {}
+
+#[doc(hidden)]
+pub trait PropsFieldsProbe<P> {
+    fn __yew_fields(&self) -> P {
+        unreachable!("only used to check the props passed to `html!`")
+    }
+}
+
+impl<P> PropsFieldsProbe<P> for &PropsProbe<P> {}
+
+#[cfg(test)]
+mod tests {
+    extern crate self as yew;
+
+    use crate::virtual_dom::{AttrValue, ListenerKind, Spread};
+    use crate::{props, Callback, Properties};
+
+    #[derive(Clone, Properties, PartialEq)]
+    struct ButtonProps {
+        label: String,
+        #[prop_or_default]
+        id: Option<String>,
+        #[prop_or_default]
+        onclick: Callback<()>,
+        #[prop(rest)]
+        rest: Spread,
+    }
+
+    #[test]
+    fn declared_props_are_not_collected_by_rest() {
+        let props = props! {
+            ButtonProps {
+                label: "Close",
+                id: "close",
+                onclick: Callback::from(|()| ()),
+                title: "Close the dialog",
+                hidden: true,
+                onmouseover: Callback::from(|_| ()),
+            }
+        };
+
+        assert_eq!(props.id.as_deref(), Some("close"));
+        let attributes: Vec<_> = props.rest.attributes().iter().collect();
+        assert_eq!(
+            attributes,
+            vec![
+                (&"hidden", &AttrValue::Borrowed("hidden")),
+                (&"title", &AttrValue::Borrowed("Close the dialog")),
+            ]
+        );
+        assert_eq!(props.rest.listeners.len(), 1);
+        assert_eq!(props.rest.listeners[0].kind(), ListenerKind::onmouseover);
+    }
+}
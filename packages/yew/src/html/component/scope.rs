@@ -6,19 +6,22 @@ use super::{
     },
     Component,
 };
-use crate::callback::Callback;
+use crate::app_handle::AppConfig;
+use crate::callback::{Callback, JsCallback, JsFunction};
 use crate::context::{ContextHandle, ContextProvider};
 use crate::html::NodeRef;
 use crate::scheduler::{self, Shared};
-use crate::utils::document;
+use crate::task::{self, TaskHandle, TaskSet};
+use crate::utils::owner_document;
 use crate::virtual_dom::{insert_node, VNode};
+use serde::de::DeserializeOwned;
 use std::any::{Any, TypeId};
 use std::cell::{Ref, RefCell};
 use std::future::Future;
 use std::ops::Deref;
 use std::rc::Rc;
 use std::{fmt, iter};
-use wasm_bindgen_futures::spawn_local;
+use wasm_bindgen::JsCast;
 use web_sys::{Element, Node};
 
 /// Untyped scope used for accessing parent scope
@@ -27,6 +30,8 @@ pub struct AnyScope {
     type_id: TypeId,
     parent: Option<Rc<AnyScope>>,
     state: Rc<dyn Any>,
+    tasks: Rc<TaskSet>,
+    config: Rc<AppConfig>,
 }
 
 impl<COMP: Component> From<Scope<COMP>> for AnyScope {
@@ -35,6 +40,8 @@ impl<COMP: Component> From<Scope<COMP>> for AnyScope {
             type_id: TypeId::of::<COMP>(),
             parent: scope.parent,
             state: scope.state,
+            tasks: scope.tasks,
+            config: scope.config,
         }
     }
 }
@@ -46,6 +53,8 @@ impl AnyScope {
             type_id: TypeId::of::<()>(),
             parent: None,
             state: Rc::new(()),
+            tasks: Rc::default(),
+            config: Rc::default(),
         }
     }
 
@@ -67,9 +76,16 @@ impl AnyScope {
                 .state
                 .downcast::<RefCell<Option<ComponentState<COMP>>>>()
                 .expect("unexpected component type"),
+            tasks: self.tasks,
+            config: self.config,
         }
     }
 
+    /// Returns the settings of the app the component belongs to
+    pub(crate) fn config(&self) -> &Rc<AppConfig> {
+        &self.config
+    }
+
     fn find_parent_scope<C: Component>(&self) -> Option<Scope<C>> {
         let expected_type_id = TypeId::of::<C>();
         iter::successors(Some(self), |scope| scope.get_parent())
@@ -90,11 +106,47 @@ impl AnyScope {
         let component = scope.get_component()?;
         Some(component.subscribe_consumer(callback, scope_clone))
     }
+
+    /// Accesses a projection of the value provided by a parent `ContextProvider` component of
+    /// the same type.
+    ///
+    /// `callback` is only called when the value returned by `selector` changes, rather than
+    /// every time the context value changes.
+    pub fn context_selector<T, S, F>(
+        &self,
+        selector: F,
+        callback: Callback<S>,
+    ) -> Option<(S, ContextHandle<T>)>
+    where
+        T: Clone + PartialEq + 'static,
+        S: Clone + PartialEq + 'static,
+        F: Fn(&T) -> S + 'static,
+    {
+        let selector = Rc::new(selector);
+        let last_selected: Rc<RefCell<Option<S>>> = Rc::default();
+        let consumer = {
+            let selector = Rc::clone(&selector);
+            let last_selected = Rc::clone(&last_selected);
+            Callback::from(move |ctx: T| {
+                let selected = selector(&ctx);
+                if last_selected.borrow().as_ref() != Some(&selected) {
+                    *last_selected.borrow_mut() = Some(selected.clone());
+                    callback.emit(selected);
+                }
+            })
+        };
+
+        let (ctx, handle) = self.context(consumer)?;
+        let selected = selector(&ctx);
+        *last_selected.borrow_mut() = Some(selected.clone());
+        Some((selected, handle))
+    }
 }
 
 pub(crate) trait Scoped {
     fn to_any(&self) -> AnyScope;
     fn root_vnode(&self) -> Option<Ref<'_, VNode>>;
+    fn set_parent(&self, parent: &Element);
     fn destroy(&mut self);
 }
 
@@ -114,8 +166,16 @@ impl<COMP: Component> Scoped for Scope<COMP> {
         }))
     }
 
+    /// Updates the parent element after the component's nodes were moved into it
+    fn set_parent(&self, parent: &Element) {
+        if let Some(state) = self.state.borrow_mut().as_mut() {
+            state.set_parent(parent.clone());
+        }
+    }
+
     /// Process an event to destroy a component
     fn destroy(&mut self) {
+        self.tasks.abort_all();
         self.process(ComponentLifecycleEvent::Destroy);
     }
 }
@@ -124,6 +184,8 @@ impl<COMP: Component> Scoped for Scope<COMP> {
 pub struct Scope<COMP: Component> {
     parent: Option<Rc<AnyScope>>,
     state: Shared<Option<ComponentState<COMP>>>,
+    tasks: Rc<TaskSet>,
+    config: Rc<AppConfig>,
 }
 
 impl<COMP: Component> fmt::Debug for Scope<COMP> {
@@ -137,6 +199,8 @@ impl<COMP: Component> Clone for Scope<COMP> {
         Scope {
             parent: self.parent.clone(),
             state: self.state.clone(),
+            tasks: self.tasks.clone(),
+            config: self.config.clone(),
         }
     }
 }
@@ -157,10 +221,30 @@ impl<COMP: Component> Scope<COMP> {
         })
     }
 
+    /// Creates the scope of a child component, which belongs to the same app as its parent.
     pub(crate) fn new(parent: Option<AnyScope>) -> Self {
+        let config = match &parent {
+            Some(parent) => Rc::clone(&parent.config),
+            None => Rc::default(),
+        };
+        Self::with_config(parent, config)
+    }
+
+    /// Creates the scope of the root component of an app.
+    pub(crate) fn new_root(config: Rc<AppConfig>) -> Self {
+        Self::with_config(None, config)
+    }
+
+    fn with_config(parent: Option<AnyScope>, config: Rc<AppConfig>) -> Self {
         let parent = parent.map(Rc::new);
         let state = Rc::new(RefCell::new(None));
-        Scope { parent, state }
+        let tasks = Rc::default();
+        Scope {
+            parent,
+            state,
+            tasks,
+            config,
+        }
     }
 
     /// Mounts a component with `props` to the specified `element` in the DOM.
@@ -172,7 +256,7 @@ impl<COMP: Component> Scope<COMP> {
         props: Rc<COMP::Properties>,
     ) {
         let placeholder = {
-            let placeholder: Node = document().create_text_node("").into();
+            let placeholder: Node = owner_document(&parent).create_text_node("").into();
             insert_node(&placeholder, &parent, next_sibling.get().as_ref());
             node_ref.set(Some(placeholder.clone()));
             VNode::VRef(placeholder)
@@ -198,6 +282,11 @@ impl<COMP: Component> Scope<COMP> {
         self.process(UpdateEvent::Properties(props, node_ref, next_sibling).into());
     }
 
+    /// Moves the rendered nodes of the component into `parent`.
+    pub(crate) fn reparent(&self, parent: Element) {
+        self.process(UpdateEvent::Reparent(parent).into());
+    }
+
     pub(crate) fn process(&self, event: ComponentLifecycleEvent<COMP>) {
         self.schedule(event);
         scheduler::start();
@@ -293,6 +382,36 @@ impl<COMP: Component> Scope<COMP> {
         }
     }
 
+    /// Creates a JavaScript function which will send a message to the linked
+    /// component's update method when called from JS.
+    ///
+    /// The argument is converted with [`JsCast`]. The returned handle owns the function, which
+    /// becomes a no-op once the handle is dropped, so keep it around for as long as JS may call
+    /// the function, for example in a field of the component.
+    pub fn js_callback<F, IN, M>(&self, function: F) -> JsFunction
+    where
+        M: Into<COMP::Message>,
+        F: Fn(IN) -> M + 'static,
+        IN: JsCast + 'static,
+    {
+        self.callback(function).into_js_function()
+    }
+
+    /// Creates a JavaScript function which will send a message to the linked
+    /// component's update method when called from JS.
+    ///
+    /// The argument is decoded with `serde`, see [`JsCallback`]. Like with
+    /// [`js_callback`](Self::js_callback), the function becomes a no-op once the returned handle
+    /// is dropped.
+    pub fn js_callback_serde<F, IN, M>(&self, function: F) -> JsCallback<IN>
+    where
+        M: Into<COMP::Message>,
+        F: Fn(IN) -> M + 'static,
+        IN: DeserializeOwned + 'static,
+    {
+        JsCallback::from(self.callback(function))
+    }
+
     /// Creates a `Callback` from an `FnOnce` which will send a message
     /// to the linked component's update method when invoked.
     ///
@@ -417,9 +536,12 @@ impl<COMP: Component> Scope<COMP> {
     /// This method processes a Future that returns a message and sends it back to the component's
     /// loop.
     ///
+    /// The returned [`TaskHandle`] can be used to abort the future. Pending futures are
+    /// aborted automatically when the component is destroyed.
+    ///
     /// # Panics
     /// If the future panics, then the promise will not resolve, and will leak.
-    pub fn send_future<F, M>(&self, future: F)
+    pub fn send_future<F, M>(&self, future: F) -> TaskHandle
     where
         M: Into<COMP::Message>,
         F: Future<Output = M> + 'static,
@@ -429,13 +551,17 @@ impl<COMP: Component> Scope<COMP> {
             let message: COMP::Message = future.await.into();
             link.send_message(message);
         };
-        spawn_local(js_future);
+        self.spawn_local(js_future)
     }
 
     /// Registers a Future that resolves to multiple messages.
+    ///
+    /// The returned [`TaskHandle`] can be used to abort the future. Pending futures are
+    /// aborted automatically when the component is destroyed.
+    ///
     /// # Panics
     /// If the future panics, then the promise will not resolve, and will leak.
-    pub fn send_future_batch<F>(&self, future: F)
+    pub fn send_future_batch<F>(&self, future: F) -> TaskHandle
     where
         F: Future<Output = Vec<COMP::Message>> + 'static,
     {
@@ -444,7 +570,21 @@ impl<COMP: Component> Scope<COMP> {
             let messages: Vec<COMP::Message> = future.await;
             link.send_message_batch(messages);
         };
-        spawn_local(js_future);
+        self.spawn_local(js_future)
+    }
+
+    /// Aborts all futures spawned through this scope which haven't completed yet.
+    pub fn abort_futures(&self) {
+        self.tasks.abort_all();
+    }
+
+    fn spawn_local<F>(&self, future: F) -> TaskHandle
+    where
+        F: Future<Output = ()> + 'static,
+    {
+        let handle = task::spawn_local(future);
+        self.tasks.push(handle.clone());
+        handle
     }
 
     /// Accesses a value provided by a parent `ContextProvider` component of the
@@ -455,6 +595,21 @@ impl<COMP: Component> Scope<COMP> {
     ) -> Option<(T, ContextHandle<T>)> {
         self.to_any().context(callback)
     }
+
+    /// Accesses a projection of the value provided by a parent `ContextProvider` component of
+    /// the same type. See [`AnyScope::context_selector`].
+    pub fn context_selector<T, S, F>(
+        &self,
+        selector: F,
+        callback: Callback<S>,
+    ) -> Option<(S, ContextHandle<T>)>
+    where
+        T: Clone + PartialEq + 'static,
+        S: Clone + PartialEq + 'static,
+        F: Fn(&T) -> S + 'static,
+    {
+        self.to_any().context_selector(selector, callback)
+    }
 }
 
 /// Defines a message type that can be sent to a component.
@@ -196,6 +196,33 @@ pub use yew_macro::html;
 /// [`ChildrenRenderer<ListItem>`]: ./html/struct.ChildrenRenderer.html
 pub use yew_macro::html_nested;
 
+/// This macro is similar to [`html!`], but reads the template from a file.
+///
+/// The path is relative to the directory containing the `Cargo.toml` of the crate. The file is
+/// read at compile time and uses the same syntax as the input of [`html!`], including Rust
+/// expressions in braces, which can refer to the variables in scope. The crate is rebuilt when the
+/// template changes.
+///
+/// Syntax errors in the template are reported with its path and the line and column of the error.
+///
+/// # Example
+///
+/// ```ignore
+/// // templates/card.html:
+/// // <div class="card">
+/// //     <h2>{ title }</h2>
+/// // </div>
+///
+/// use yew::{html_file, Html};
+///
+/// fn card(title: &str) -> Html {
+///     html_file!("templates/card.html")
+/// }
+/// ```
+///
+/// [`html!`]: ./macro.html.html
+pub use yew_macro::html_file;
+
 /// Build [`Properties`] outside of the [`html!`] macro.
 ///
 /// It's already possible to create properties like normal Rust structs
@@ -248,22 +275,68 @@ pub use yew_macro::html_nested;
 /// [Yew Docs]: https://yew.rs/concepts/components/properties
 pub use yew_macro::props;
 
+/// This macro compiles a stylesheet which is scoped to a generated class name.
+///
+/// It takes a string literal containing css and returns a [`Style`], which can be used like a class
+/// with [`classes!`]. The stylesheet is added to the `<head>` of the document the first time the
+/// class is used.
+///
+/// Declarations at the top level apply to the generated class. Rules can be nested: `&` refers to
+/// the parent selector, selectors starting with `:` are pseudo-classes of the parent and any other
+/// selector matches descendants of the parent. `@media` and `@supports` rules can be nested too.
+///
+/// ```
+/// # use yew::{classes, css, html};
+/// let button = css!(
+///     "
+///     color: white;
+///     background: rebeccapurple;
+///     &:hover, &:focus { background: indigo; }
+///     @media (prefers-reduced-motion: no-preference) {
+///         transition: background 0.2s;
+///     }
+///     "
+/// );
+///
+/// html! {
+///     <button class={classes!("button", button)}>{ "Click me" }</button>
+/// };
+/// ```
+///
+/// [`Style`]: ./style/struct.Style.html
+/// [`classes!`]: ./macro.classes.html
+pub use yew_macro::css;
+
+/// An alias of [`css!`](./macro.css.html).
+pub use yew_macro::style;
+
 /// This module contains macros which implements html! macro and JSX-like templates
 pub mod macros {
     pub use crate::classes;
+    pub use crate::css;
     pub use crate::html;
+    pub use crate::html_file;
     pub use crate::html_nested;
     pub use crate::props;
+    pub use crate::style;
 }
 
 mod app_handle;
 pub mod callback;
 pub mod context;
+pub mod custom_element;
+pub mod fetch;
+pub mod format;
 pub mod functional;
 pub mod html;
 pub mod scheduler;
+pub mod storage;
+pub mod style;
+pub mod task;
+pub mod transition;
 pub mod utils;
 pub mod virtual_dom;
+pub mod websocket;
 
 pub use web_sys;
 
@@ -280,8 +353,8 @@ pub mod events {
     };
 }
 
-pub use crate::app_handle::AppHandle;
-use web_sys::Element;
+pub use crate::app_handle::{App, AppBuilder, AppHandle};
+use web_sys::{Element, ShadowRoot};
 
 thread_local! {
     static PANIC_HOOK_IS_SET: Cell<bool> = Cell::new(false);
@@ -295,7 +368,7 @@ pub fn set_custom_panic_hook(hook: Box<dyn Fn(&PanicInfo<'_>) + Sync + Send + 's
     PANIC_HOOK_IS_SET.with(|hook_is_set| hook_is_set.set(true));
 }
 
-fn set_default_panic_hook() {
+pub(crate) fn set_default_panic_hook() {
     if !PANIC_HOOK_IS_SET.with(|hook_is_set| hook_is_set.replace(true)) {
         std::panic::set_hook(Box::new(console_error_panic_hook::hook));
     }
@@ -343,8 +416,9 @@ pub fn start_app_with_props_in_element<COMP>(
 where
     COMP: Component,
 {
-    set_default_panic_hook();
-    AppHandle::<COMP>::mount_with_props(element, Rc::new(props))
+    App::<COMP>::builder_with_props(props)
+        .mount_point(element)
+        .mount()
 }
 
 /// The main entry point of a Yew application.
@@ -353,13 +427,31 @@ pub fn start_app_with_props<COMP>(props: COMP::Properties) -> AppHandle<COMP>
 where
     COMP: Component,
 {
-    start_app_with_props_in_element(
-        crate::utils::document()
-            .body()
-            .expect("no body node found")
-            .into(),
-        props,
-    )
+    App::<COMP>::builder_with_props(props).mount()
+}
+
+/// Starts a Yew app inside a shadow root, isolating its styles from the rest of the page.
+/// See [`AppBuilder::mount_in_shadow_root`].
+pub fn start_app_in_shadow_root<COMP>(root: ShadowRoot) -> AppHandle<COMP>
+where
+    COMP: Component,
+    COMP::Properties: Default,
+{
+    start_app_with_props_in_shadow_root(root, COMP::Properties::default())
+}
+
+/// Same as `start_app_in_shadow_root(...)` but allows to start an Yew application with
+/// properties.
+pub fn start_app_with_props_in_shadow_root<COMP>(
+    root: ShadowRoot,
+    props: COMP::Properties,
+) -> AppHandle<COMP>
+where
+    COMP: Component,
+{
+    App::<COMP>::builder_with_props(props)
+        .mount_in_shadow_root(root)
+        .mount()
 }
 
 /// The main entry point of a Yew application.
@@ -371,8 +463,9 @@ pub fn start_app_with_props_as_body<COMP>(props: COMP::Properties) -> AppHandle<
 where
     COMP: Component,
 {
-    set_default_panic_hook();
-    AppHandle::<COMP>::mount_as_body_with_props(Rc::new(props))
+    App::<COMP>::builder_with_props(props)
+        .mount_as_body()
+        .mount()
 }
 
 /// The Yew Prelude
@@ -391,10 +484,9 @@ pub mod prelude {
     pub use crate::html::{
         Children, ChildrenWithProps, Classes, Component, Context, Html, NodeRef, Properties,
     };
-    pub use crate::macros::{classes, html, html_nested};
+    pub use crate::macros::{classes, html, html_file, html_nested};
 
     pub use crate::functional::*;
 }
 
 pub use self::prelude::*;
-use std::rc::Rc;
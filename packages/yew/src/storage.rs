@@ -0,0 +1,261 @@
+//! This module contains typed access to the browser's
+//! [Web Storage](https://developer.mozilla.org/en-US/docs/Web/API/Web_Storage_API).
+//!
+//! Values are encoded with a [`Format`], JSON being the default. Data encoded with a binary
+//! format is stored as base64 since storage only holds strings.
+//!
+//! ```
+//! # use yew::storage::{Storage, StorageError};
+//! # fn dont_execute() -> Result<(), StorageError> {
+//! let storage = Storage::local().with_namespace("todomvc");
+//! storage.set("entries", &vec!["Buy milk"])?;
+//! let entries: Option<Vec<String>> = storage.get("entries")?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::format::{Format, FormatError, Json};
+use crate::utils::window;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::convert::TryFrom;
+use std::fmt;
+use std::marker::PhantomData;
+use thiserror::Error;
+use wasm_bindgen::{JsCast, JsValue};
+
+/// An error that occurred while accessing storage.
+#[derive(Debug, Error)]
+pub enum StorageError {
+    /// The storage area isn't available, for example because the user disabled it.
+    #[error("storage is not available")]
+    Unavailable,
+    /// The value couldn't be stored because the storage quota was exceeded.
+    #[error("storage quota exceeded")]
+    QuotaExceeded,
+    /// The storage area raised an error.
+    #[error("storage error: {0}")]
+    Js(String),
+    /// The value couldn't be encoded or decoded.
+    #[error(transparent)]
+    Format(#[from] FormatError),
+}
+
+impl StorageError {
+    fn from_js(err: JsValue) -> Self {
+        let name = js_sys::Reflect::get(&err, &JsValue::from_str("name"))
+            .ok()
+            .and_then(|name| name.as_string());
+        match name.as_deref() {
+            // Firefox used to report a different name for the same error
+            Some("QuotaExceededError") | Some("NS_ERROR_DOM_QUOTA_REACHED") => {
+                StorageError::QuotaExceeded
+            }
+            _ => {
+                let message = match err.dyn_ref::<js_sys::Error>() {
+                    Some(err) => String::from(err.message()),
+                    None => err.as_string().unwrap_or_else(|| format!("{:?}", err)),
+                };
+                StorageError::Js(message)
+            }
+        }
+    }
+}
+
+/// The storage areas provided by the browser.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StorageArea {
+    /// `localStorage`, which persists across browser sessions.
+    Local,
+    /// `sessionStorage`, which is cleared when the page session ends.
+    Session,
+}
+
+impl StorageArea {
+    /// Returns the underlying `web_sys::Storage` object.
+    pub fn get(self) -> Result<web_sys::Storage, StorageError> {
+        let storage = match self {
+            StorageArea::Local => window().local_storage(),
+            StorageArea::Session => window().session_storage(),
+        };
+        storage
+            .map_err(StorageError::from_js)?
+            .ok_or(StorageError::Unavailable)
+    }
+}
+
+/// Typed access to a storage area.
+///
+/// Keys can be namespaced to keep the data of different apps or components on the same origin
+/// apart.
+pub struct Storage<F = Json> {
+    area: StorageArea,
+    namespace: Option<String>,
+    _format: PhantomData<F>,
+}
+
+impl<F> Clone for Storage<F> {
+    fn clone(&self) -> Self {
+        Self {
+            area: self.area,
+            namespace: self.namespace.clone(),
+            _format: PhantomData,
+        }
+    }
+}
+
+impl<F> PartialEq for Storage<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.area == other.area && self.namespace == other.namespace
+    }
+}
+
+impl<F> fmt::Debug for Storage<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Storage")
+            .field("area", &self.area)
+            .field("namespace", &self.namespace)
+            .finish()
+    }
+}
+
+impl Storage {
+    /// Typed access to `localStorage`.
+    pub fn local() -> Self {
+        Self::new(StorageArea::Local)
+    }
+
+    /// Typed access to `sessionStorage`.
+    pub fn session() -> Self {
+        Self::new(StorageArea::Session)
+    }
+
+    /// Typed access to the given storage area.
+    pub fn new(area: StorageArea) -> Self {
+        Self {
+            area,
+            namespace: None,
+            _format: PhantomData,
+        }
+    }
+}
+
+impl<F> Storage<F> {
+    /// Prefixes all keys with `namespace`.
+    pub fn with_namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
+    /// Encodes values with the format `G` instead.
+    pub fn with_format<G: Format>(self) -> Storage<G> {
+        Storage {
+            area: self.area,
+            namespace: self.namespace,
+            _format: PhantomData,
+        }
+    }
+
+    /// The storage area values are stored in.
+    pub fn area(&self) -> StorageArea {
+        self.area
+    }
+
+    /// Returns the key under which the value for `key` is actually stored.
+    pub fn storage_key(&self, key: &str) -> String {
+        match &self.namespace {
+            Some(namespace) => format!("{}:{}", namespace, key),
+            None => key.to_owned(),
+        }
+    }
+}
+
+impl<F: Format> Storage<F> {
+    /// Reads and decodes the value stored under `key`.
+    pub fn get<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>, StorageError> {
+        let raw = self
+            .area
+            .get()?
+            .get_item(&self.storage_key(key))
+            .map_err(StorageError::from_js)?;
+        raw.map(|raw| self.decode(&raw)).transpose()
+    }
+
+    /// Encodes and stores `value` under `key`.
+    pub fn set<T: Serialize + ?Sized>(&self, key: &str, value: &T) -> Result<(), StorageError> {
+        let raw = self.encode(value)?;
+        self.area
+            .get()?
+            .set_item(&self.storage_key(key), &raw)
+            .map_err(StorageError::from_js)
+    }
+
+    /// Removes the value stored under `key`.
+    pub fn remove(&self, key: &str) -> Result<(), StorageError> {
+        self.area
+            .get()?
+            .remove_item(&self.storage_key(key))
+            .map_err(StorageError::from_js)
+    }
+
+    /// Encodes a value into the string that is stored.
+    pub fn encode<T: Serialize + ?Sized>(&self, value: &T) -> Result<String, StorageError> {
+        let data = F::encode(value)?;
+        if F::IS_TEXT {
+            String::from_utf8(data).map_err(|err| FormatError::Encode(err.to_string()).into())
+        } else {
+            window()
+                .btoa(&to_binary_string(&data))
+                .map_err(StorageError::from_js)
+        }
+    }
+
+    /// Decodes a stored string.
+    pub fn decode<T: DeserializeOwned>(&self, raw: &str) -> Result<T, StorageError> {
+        if F::IS_TEXT {
+            Ok(F::decode(raw.as_bytes())?)
+        } else {
+            let binary = window().atob(raw).map_err(StorageError::from_js)?;
+            let data = from_binary_string(&binary)
+                .ok_or_else(|| FormatError::Decode("invalid base64 data".into()))?;
+            Ok(F::decode(&data)?)
+        }
+    }
+}
+
+/// Converts bytes into the "binary string" representation expected by `btoa`.
+fn to_binary_string(data: &[u8]) -> String {
+    data.iter().map(|&byte| char::from(byte)).collect()
+}
+
+/// Converts a "binary string" returned by `atob` back into bytes.
+fn from_binary_string(binary: &str) -> Option<Vec<u8>> {
+    binary
+        .chars()
+        .map(|c| u8::try_from(u32::from(c)).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn namespaces_keys() {
+        let storage = Storage::local();
+        assert_eq!(storage.storage_key("entries"), "entries");
+
+        let storage = storage.with_namespace("todomvc");
+        assert_eq!(storage.storage_key("entries"), "todomvc:entries");
+    }
+
+    #[test]
+    fn binary_strings_round_trip() {
+        let data: Vec<u8> = (0..=255).collect();
+        let binary = to_binary_string(&data);
+
+        assert_eq!(binary.chars().count(), 256);
+        assert_eq!(from_binary_string(&binary), Some(data));
+        assert_eq!(from_binary_string("\u{100}"), None);
+    }
+}
@@ -0,0 +1,177 @@
+//! This module contains the [`Style`] type created by the [`css!`](crate::css) macro.
+
+use crate::html::{Classes, IntoPropValue};
+use crate::virtual_dom::AttrValue;
+use indexmap::IndexSet;
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::fmt;
+
+/// The attribute which identifies the `<style>` elements of [`Style`]s.
+const STYLE_ATTRIBUTE: &str = "data-yew-style";
+
+thread_local! {
+    static REGISTRY: RefCell<IndexSet<Style>> = RefCell::new(IndexSet::new());
+}
+
+/// A stylesheet which is scoped to a generated class name.
+///
+/// The stylesheet is added to the `<head>` of the document the first time the class name is used,
+/// for example by passing the style to [`classes!`](crate::classes).
+///
+/// # Example
+///
+/// ```
+/// use yew::{classes, css, html, Html};
+///
+/// fn card(title: &str) -> Html {
+///     let style = css!(
+///         r#"
+///         padding: 1em;
+///         border-radius: 4px;
+///
+///         h2 { margin: 0; }
+///         &:hover { box-shadow: 0 2px 8px rgba(0, 0, 0, 0.2); }
+///
+///         @media (max-width: 600px) {
+///             padding: 0.5em;
+///         }
+///         "#
+///     );
+///     html! {
+///         <div class={classes!("card", style)}>
+///             <h2>{ title }</h2>
+///         </div>
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Style {
+    class: &'static str,
+    css: &'static str,
+}
+
+impl Style {
+    #[doc(hidden)]
+    pub const fn __new(class: &'static str, css: &'static str) -> Self {
+        Self { class, css }
+    }
+
+    /// Returns the generated class name and registers the stylesheet if this is the first use.
+    pub fn class_name(&self) -> &'static str {
+        self.register();
+        self.class
+    }
+
+    /// Returns the compiled stylesheet.
+    pub fn css(&self) -> &'static str {
+        self.css
+    }
+
+    /// Registers the stylesheet.
+    ///
+    /// In the browser the first registration adds a `<style>` element to the `<head>` of the
+    /// document, unless the server already rendered one for this style.
+    pub fn register(&self) {
+        let registered = REGISTRY.with(|registry| registry.borrow_mut().insert(*self));
+        if registered {
+            #[cfg(target_arch = "wasm32")]
+            self.mount();
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn mount(&self) {
+        let document = crate::utils::document();
+        let head = match document.head() {
+            Some(head) => head,
+            None => return,
+        };
+        let selector = format!("style[{}=\"{}\"]", STYLE_ATTRIBUTE, self.class);
+        if let Ok(Some(_)) = head.query_selector(&selector) {
+            return;
+        }
+
+        let element = document
+            .create_element("style")
+            .expect("failed to create style element");
+        element
+            .set_attribute(STYLE_ATTRIBUTE, self.class)
+            .expect("failed to set style attribute");
+        element.set_text_content(Some(self.css));
+        head.append_child(&element)
+            .expect("failed to add style to head");
+    }
+}
+
+impl fmt::Display for Style {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.class_name())
+    }
+}
+
+impl From<Style> for Classes {
+    fn from(style: Style) -> Self {
+        let mut classes = Classes::with_capacity(1);
+        // SAFETY: generated class names don't contain whitespace
+        unsafe { classes.unchecked_push(style.class_name()) };
+        classes
+    }
+}
+
+impl IntoPropValue<AttrValue> for Style {
+    fn into_prop_value(self) -> AttrValue {
+        Cow::Borrowed(self.class_name())
+    }
+}
+
+impl IntoPropValue<Option<AttrValue>> for Style {
+    fn into_prop_value(self) -> Option<AttrValue> {
+        Some(self.into_prop_value())
+    }
+}
+
+/// Returns every registered [`Style`] in the order they were first used.
+pub fn registered_styles() -> Vec<Style> {
+    REGISTRY.with(|registry| registry.borrow().iter().copied().collect())
+}
+
+/// Renders every registered [`Style`] as a `<style>` element.
+///
+/// A server renders these into the `<head>` of the page. The browser reuses them instead of adding
+/// the styles again.
+pub fn render_styles() -> String {
+    registered_styles()
+        .into_iter()
+        .map(|style| {
+            format!(
+                "<style {}=\"{}\">{}</style>",
+                STYLE_ATTRIBUTE, style.class, style.css
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn styles_are_registered_once_on_use() {
+        let style = Style::__new("yew-test", ".yew-test{color:red}");
+        assert!(!registered_styles().contains(&style));
+
+        let classes = Classes::from(style);
+        assert!(classes.contains("yew-test"));
+        assert_eq!(style.to_string(), "yew-test");
+        assert_eq!(
+            registered_styles()
+                .into_iter()
+                .filter(|registered| *registered == style)
+                .count(),
+            1
+        );
+        assert!(render_styles()
+            .contains("<style data-yew-style=\"yew-test\">.yew-test{color:red}</style>"));
+    }
+}
@@ -0,0 +1,137 @@
+//! This module contains handles for futures spawned by Yew.
+//!
+//! Futures spawned through a [`Scope`](crate::html::Scope) or a hook are tracked by a
+//! [`TaskHandle`] which can be used to abort them before they complete.
+
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+
+#[derive(Default)]
+struct TaskState {
+    aborted: Cell<bool>,
+    finished: Cell<bool>,
+    waker: RefCell<Option<Waker>>,
+}
+
+/// A handle to a spawned future.
+///
+/// Dropping the handle does **not** abort the future. Use [`TaskHandle::abort`] to stop it
+/// from being polled again, which drops the future at the next opportunity.
+#[derive(Clone, Default)]
+pub struct TaskHandle {
+    state: Rc<TaskState>,
+}
+
+impl fmt::Debug for TaskHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TaskHandle")
+            .field("aborted", &self.state.aborted.get())
+            .field("finished", &self.state.finished.get())
+            .finish()
+    }
+}
+
+impl TaskHandle {
+    /// Aborts the future. It won't be polled again and its output, if any, is discarded.
+    ///
+    /// Aborting a task that has already finished is a no-op.
+    pub fn abort(&self) {
+        if self.state.finished.get() || self.state.aborted.replace(true) {
+            return;
+        }
+        // Wake the task so that the executor drops the future right away instead of
+        // holding on to it until whatever it's waiting on resolves.
+        if let Some(waker) = self.state.waker.borrow_mut().take() {
+            waker.wake();
+        }
+    }
+
+    /// Returns `true` if the task was aborted before it could finish.
+    pub fn is_aborted(&self) -> bool {
+        self.state.aborted.get()
+    }
+
+    /// Returns `true` if the future ran to completion.
+    pub fn is_finished(&self) -> bool {
+        self.state.finished.get()
+    }
+}
+
+/// Future wrapper which stops polling the inner future once its task is aborted.
+struct Abortable {
+    future: Pin<Box<dyn Future<Output = ()>>>,
+    state: Rc<TaskState>,
+}
+
+impl Future for Abortable {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.state.aborted.get() {
+            return Poll::Ready(());
+        }
+
+        {
+            let mut waker = self.state.waker.borrow_mut();
+            match waker.as_ref() {
+                Some(waker) if waker.will_wake(cx.waker()) => {}
+                _ => *waker = Some(cx.waker().clone()),
+            }
+        }
+
+        match self.future.as_mut().poll(cx) {
+            Poll::Ready(()) => {
+                self.state.finished.set(true);
+                self.state.waker.borrow_mut().take();
+                Poll::Ready(())
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// Spawns a future on the current thread and returns a handle which can abort it.
+pub(crate) fn spawn_local<F>(future: F) -> TaskHandle
+where
+    F: Future<Output = ()> + 'static,
+{
+    let handle = TaskHandle::default();
+    wasm_bindgen_futures::spawn_local(Abortable {
+        future: Box::pin(future),
+        state: Rc::clone(&handle.state),
+    });
+    handle
+}
+
+/// The set of tasks spawned by a single component.
+#[derive(Default)]
+pub(crate) struct TaskSet {
+    tasks: RefCell<Vec<TaskHandle>>,
+}
+
+impl fmt::Debug for TaskSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TaskSet<_>")
+    }
+}
+
+impl TaskSet {
+    /// Track a task. Finished tasks are pruned to keep the set from growing indefinitely.
+    pub(crate) fn push(&self, handle: TaskHandle) {
+        let mut tasks = self.tasks.borrow_mut();
+        tasks.retain(|task| !task.is_finished() && !task.is_aborted());
+        tasks.push(handle);
+    }
+
+    /// Abort every tracked task.
+    pub(crate) fn abort_all(&self) {
+        let tasks = std::mem::take(&mut *self.tasks.borrow_mut());
+        for task in tasks {
+            task.abort();
+        }
+    }
+}
@@ -0,0 +1,613 @@
+//! This module defines the [`Transition`] and [`TransitionGroup`] components.
+//!
+//! Both components apply CSS classes to their children while they enter or leave the DOM, so
+//! the transitions and animations themselves are defined in CSS. For a transition named `fade`
+//! the following classes are used:
+//!
+//! | Class               | Applied                                                            |
+//! |---------------------|--------------------------------------------------------------------|
+//! | `fade-enter-from`   | when the element is inserted, removed once its styles were applied |
+//! | `fade-enter-active` | during the whole enter phase                                       |
+//! | `fade-enter-to`     | after `fade-enter-from` was removed until the enter phase ends     |
+//! | `fade-leave-from`   | when the leave phase starts, removed once its styles were applied  |
+//! | `fade-leave-active` | during the whole leave phase                                       |
+//! | `fade-leave-to`     | after `fade-leave-from` was removed until the element is removed   |
+//! | `fade-move`         | while an element of a [`TransitionGroup`] moves to a new position  |
+//!
+//! A phase ends when the element fires `transitionend` or `animationend`, or when the
+//! `timeout` passes. Only then is a leaving element actually removed from the DOM.
+//!
+//! The classes are added through `classList`, next to the ones set with the `class` attribute.
+//! A render that changes the `class` of a child replaces all of them, so they're added again
+//! whenever the transition component renders. A child component that re-renders on its own
+//! and changes its `class` drops them until then.
+//!
+//! ```
+//! # use yew::prelude::*;
+//! # use yew::transition::Transition;
+//! #[function_component(Toggle)]
+//! fn toggle() -> Html {
+//!     let show = use_state(|| true);
+//!     let onclick = {
+//!         let show = show.clone();
+//!         Callback::from(move |_| show.set(!*show))
+//!     };
+//!
+//!     html! {
+//!         <>
+//!             <button {onclick}>{ "Toggle" }</button>
+//!             <Transition name="fade" show={*show}>
+//!                 <p>{ "Hello!" }</p>
+//!             </Transition>
+//!         </>
+//!     }
+//! }
+//! ```
+
+use crate::html::NodeRef;
+use crate::virtual_dom::{Key, VNode};
+use crate::{html, Callback, Children, Component, Context, Html, Properties};
+use gloo::events::EventListener;
+use gloo::timers::callback::Timeout;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::mem;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement};
+
+/// Message sent to [`Transition`] and [`TransitionGroup`] when a phase ends.
+#[derive(Debug)]
+pub struct TransitionDone {
+    key: Option<Key>,
+    generation: usize,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum PhaseKind {
+    Enter,
+    Leave,
+}
+
+impl PhaseKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            PhaseKind::Enter => "enter",
+            PhaseKind::Leave => "leave",
+        }
+    }
+}
+
+/// A running enter, leave or move phase of an element.
+///
+/// Dropping the phase removes the classes it applied and stops listening for its end.
+struct Phase {
+    element: Element,
+    moving: bool,
+    classes: Vec<String>,
+    _listeners: [EventListener; 2],
+    _timeout: Timeout,
+}
+
+impl Phase {
+    /// Starts an enter or leave phase.
+    fn start(
+        element: Element,
+        name: &str,
+        kind: PhaseKind,
+        timeout: u32,
+        on_done: Callback<()>,
+    ) -> Self {
+        let from = format!("{}-{}-from", name, kind.as_str());
+        let active = format!("{}-{}-active", name, kind.as_str());
+        let to = format!("{}-{}-to", name, kind.as_str());
+
+        let class_list = element.class_list();
+        let _ = class_list.add_2(&from, &active);
+        reflow(&element);
+        let _ = class_list.remove_1(&from);
+        let _ = class_list.add_1(&to);
+
+        Self::new(element, false, vec![active, to], timeout, on_done)
+    }
+
+    /// Starts a move phase, animating the element from its previous position which was offset
+    /// by (`dx`, `dy`) pixels from the current one.
+    fn flip(
+        element: Element,
+        name: &str,
+        (dx, dy): (f64, f64),
+        timeout: u32,
+        on_done: Callback<()>,
+    ) -> Option<Self> {
+        let style = element.dyn_ref::<HtmlElement>()?.style();
+        let class = format!("{}-move", name);
+
+        let _ = style.set_property("transform", &format!("translate({}px, {}px)", dx, dy));
+        let _ = style.set_property("transition-duration", "0s");
+        reflow(&element);
+        let _ = element.class_list().add_1(&class);
+        let _ = style.remove_property("transform");
+        let _ = style.remove_property("transition-duration");
+
+        Some(Self::new(element, true, vec![class], timeout, on_done))
+    }
+
+    fn new(
+        element: Element,
+        moving: bool,
+        classes: Vec<String>,
+        timeout: u32,
+        on_done: Callback<()>,
+    ) -> Self {
+        let listener = |event_type: &'static str| {
+            let target = element.clone();
+            let on_done = on_done.clone();
+            EventListener::new(&element, event_type, move |event| {
+                // Ignore transitions of descendants which bubble up to the element
+                if event.target().as_ref() == Some(target.as_ref()) {
+                    on_done.emit(());
+                }
+            })
+        };
+        let listeners = [listener("transitionend"), listener("animationend")];
+        let timeout = Timeout::new(timeout, move || on_done.emit(()));
+
+        Self {
+            element,
+            moving,
+            classes,
+            _listeners: listeners,
+            _timeout: timeout,
+        }
+    }
+}
+
+impl Phase {
+    /// Adds the classes of the phase again, in case a render replaced the `class` attribute.
+    fn restore_classes(&self) {
+        let class_list = self.element.class_list();
+        for class in &self.classes {
+            let _ = class_list.add_1(class);
+        }
+    }
+}
+
+impl Drop for Phase {
+    fn drop(&mut self) {
+        let class_list = self.element.class_list();
+        for class in &self.classes {
+            let _ = class_list.remove_1(class);
+        }
+    }
+}
+
+impl fmt::Debug for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Phase<_>")
+    }
+}
+
+/// Forces the browser to compute the layout, so that class changes before and after the call
+/// are not batched into a single style change.
+fn reflow(element: &Element) {
+    element.get_bounding_client_rect();
+}
+
+/// Returns the node ref of the element a child renders to.
+fn node_ref(node: &VNode) -> Option<NodeRef> {
+    match node {
+        VNode::VTag(vtag) => Some(vtag.node_ref.clone()),
+        VNode::VComp(vcomp) => Some(vcomp.node_ref.clone()),
+        _ => None,
+    }
+}
+
+fn element(node: &VNode) -> Option<Element> {
+    node_ref(node)?.cast::<Element>()
+}
+
+fn position(element: &Element) -> (f64, f64) {
+    let rect = element.get_bounding_client_rect();
+    (rect.left(), rect.top())
+}
+
+/// Props for [`Transition`]
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct TransitionProps {
+    /// The prefix of the transition classes.
+    #[prop_or(Cow::Borrowed("yew"))]
+    pub name: Cow<'static, str>,
+    /// Whether the children are shown.
+    #[prop_or(true)]
+    pub show: bool,
+    /// Whether the enter transition also runs when the component is first rendered.
+    #[prop_or_default]
+    pub appear: bool,
+    /// The maximum duration of a phase in milliseconds.
+    #[prop_or(1000)]
+    pub timeout: u32,
+    /// Called when the children finished entering.
+    #[prop_or_default]
+    pub on_after_enter: Callback<()>,
+    /// Called when the children finished leaving and were removed.
+    #[prop_or_default]
+    pub on_after_leave: Callback<()>,
+    /// The children. The classes are applied to the first element or component.
+    pub children: Children,
+}
+
+/// Applies enter and leave transitions when its children are shown or hidden.
+///
+/// When `show` becomes `false` the children stay mounted until the leave phase ends.
+/// See the [module documentation](self) for the classes that are applied.
+#[derive(Debug)]
+pub struct Transition {
+    visible: bool,
+    leaving: bool,
+    pending: Option<PhaseKind>,
+    phase: Option<Phase>,
+    generation: usize,
+}
+
+impl Component for Transition {
+    type Message = TransitionDone;
+    type Properties = TransitionProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let props = ctx.props();
+        Self {
+            visible: props.show,
+            leaving: false,
+            pending: if props.show && props.appear {
+                Some(PhaseKind::Enter)
+            } else {
+                None
+            },
+            phase: None,
+            generation: 0,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        // Ends of phases that were cancelled in the meantime are ignored
+        if msg.generation != self.generation {
+            return false;
+        }
+        self.phase = None;
+
+        let props = ctx.props();
+        if self.leaving {
+            self.leaving = false;
+            self.visible = false;
+            props.on_after_leave.emit(());
+            true
+        } else {
+            props.on_after_enter.emit(());
+            false
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>) -> bool {
+        let show = ctx.props().show;
+        if show && (!self.visible || self.leaving) {
+            // Also cancels a running leave phase
+            self.phase = None;
+            self.leaving = false;
+            self.visible = true;
+            self.pending = Some(PhaseKind::Enter);
+        } else if !show && self.visible && !self.leaving {
+            self.phase = None;
+            self.leaving = true;
+            self.pending = Some(PhaseKind::Leave);
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        if self.visible {
+            html! { <>{ ctx.props().children.clone() }</> }
+        } else {
+            html! {}
+        }
+    }
+
+    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
+        if let Some(phase) = &self.phase {
+            phase.restore_classes();
+        }
+        let kind = match self.pending.take() {
+            Some(kind) => kind,
+            None => return,
+        };
+
+        self.generation += 1;
+        let generation = self.generation;
+        let props = ctx.props();
+        let element = props.children.iter().find_map(|child| element(&child));
+        match element {
+            Some(element) => {
+                let on_done = ctx.link().callback(move |_| TransitionDone {
+                    key: None,
+                    generation,
+                });
+                self.phase = Some(Phase::start(
+                    element,
+                    &props.name,
+                    kind,
+                    props.timeout,
+                    on_done,
+                ));
+            }
+            // There is nothing to animate, so the children can be removed right away
+            None if kind == PhaseKind::Leave => ctx.link().send_message(TransitionDone {
+                key: None,
+                generation,
+            }),
+            None => {}
+        }
+    }
+}
+
+/// Props for [`TransitionGroup`]
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct TransitionGroupProps {
+    /// The prefix of the transition classes.
+    #[prop_or(Cow::Borrowed("yew"))]
+    pub name: Cow<'static, str>,
+    /// Whether the enter transition also runs for the initial children.
+    #[prop_or_default]
+    pub appear: bool,
+    /// The maximum duration of a phase in milliseconds.
+    #[prop_or(1000)]
+    pub timeout: u32,
+    /// The children. Each child should be a keyed element or component.
+    pub children: Children,
+}
+
+struct Entry {
+    key: Key,
+    node: Html,
+    leaving: bool,
+    pending: Option<PhaseKind>,
+    phase: Option<Phase>,
+    generation: usize,
+}
+
+impl Entry {
+    fn new(key: Key, node: Html, pending: Option<PhaseKind>) -> Self {
+        Self {
+            key,
+            node,
+            leaving: false,
+            pending,
+            phase: None,
+            generation: 0,
+        }
+    }
+}
+
+impl fmt::Debug for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Entry")
+            .field("key", &self.key)
+            .field("leaving", &self.leaving)
+            .finish()
+    }
+}
+
+/// Applies enter and leave transitions to keyed children that are added or removed, and move
+/// transitions to children that change their position.
+///
+/// Removed children are kept in place until their leave phase ends. Reordered children are
+/// animated with the [FLIP](https://aerotwist.com/blog/flip-your-animations/) technique: they
+/// are translated back to their previous position and the `{name}-move` class is added, so a
+/// CSS rule like `.list-move { transition: transform 0.5s; }` makes them glide into place.
+///
+/// Unkeyed children are keyed by their index.
+#[derive(Debug)]
+pub struct TransitionGroup {
+    entries: Vec<Entry>,
+    positions: HashMap<Key, (f64, f64)>,
+    generation: usize,
+}
+
+impl TransitionGroup {
+    fn children(props: &TransitionGroupProps) -> Vec<(Key, Html)> {
+        props
+            .children
+            .iter()
+            .enumerate()
+            .map(|(index, mut child)| match child.key() {
+                Some(key) => (key, child),
+                None => {
+                    let key = Key::from(index);
+                    match &mut child {
+                        VNode::VTag(vtag) => vtag.key = Some(key.clone()),
+                        VNode::VComp(vcomp) => vcomp.key = Some(key.clone()),
+                        VNode::VList(vlist) => vlist.key = Some(key.clone()),
+                        VNode::VText(_) | VNode::VRef(_) => {
+                            child = VNode::VList(crate::virtual_dom::VList::with_children(
+                                vec![child],
+                                Some(key.clone()),
+                            ))
+                        }
+                    }
+                    (key, child)
+                }
+            })
+            .collect()
+    }
+
+    /// Merges the new children into the current entries.
+    ///
+    /// Entries of removed children start leaving and stay at their position relative to the
+    /// entries that precede them.
+    fn merge(&mut self, children: Vec<(Key, Html)>) {
+        let keys: HashSet<&Key> = children.iter().map(|(key, _)| key).collect();
+
+        let mut removed = Vec::new();
+        let mut removed_before = HashMap::new();
+        let mut kept = HashMap::new();
+        for mut entry in self.entries.drain(..) {
+            if keys.contains(&entry.key) {
+                if !removed.is_empty() {
+                    removed_before.insert(entry.key.clone(), mem::take(&mut removed));
+                }
+                kept.insert(entry.key.clone(), entry);
+            } else {
+                if !entry.leaving {
+                    entry.leaving = true;
+                    entry.phase = None;
+                    entry.pending = Some(PhaseKind::Leave);
+                }
+                removed.push(entry);
+            }
+        }
+
+        let mut entries = Vec::with_capacity(children.len() + removed.len());
+        for (key, node) in children {
+            if let Some(removed) = removed_before.remove(&key) {
+                entries.extend(removed);
+            }
+            let entry = match kept.remove(&key) {
+                Some(mut entry) => {
+                    if entry.leaving {
+                        // The child came back before it finished leaving
+                        entry.leaving = false;
+                        entry.phase = None;
+                        entry.pending = Some(PhaseKind::Enter);
+                    }
+                    entry.node = node;
+                    entry
+                }
+                None => Entry::new(key, node, Some(PhaseKind::Enter)),
+            };
+            entries.push(entry);
+        }
+        entries.extend(removed);
+        self.entries = entries;
+    }
+}
+
+impl Component for TransitionGroup {
+    type Message = TransitionDone;
+    type Properties = TransitionGroupProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let props = ctx.props();
+        let pending = if props.appear {
+            Some(PhaseKind::Enter)
+        } else {
+            None
+        };
+        Self {
+            entries: Self::children(props)
+                .into_iter()
+                .map(|(key, node)| Entry::new(key, node, pending))
+                .collect(),
+            positions: HashMap::new(),
+            generation: 0,
+        }
+    }
+
+    fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let TransitionDone { key, generation } = msg;
+        let key = match key {
+            Some(key) => key,
+            None => return false,
+        };
+        let index = match self
+            .entries
+            .iter()
+            .position(|entry| entry.key == key && entry.generation == generation)
+        {
+            Some(index) => index,
+            // The phase was cancelled in the meantime
+            None => return false,
+        };
+
+        let entry = &mut self.entries[index];
+        entry.phase = None;
+        if entry.leaving {
+            self.entries.remove(index);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>) -> bool {
+        // Remember where the children are before the layout changes
+        self.positions = self
+            .entries
+            .iter()
+            .filter(|entry| !entry.leaving)
+            .filter_map(|entry| Some((entry.key.clone(), position(&element(&entry.node)?))))
+            .collect();
+
+        self.merge(Self::children(ctx.props()));
+        true
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        html! {
+            <>{ for self.entries.iter().map(|entry| entry.node.clone()) }</>
+        }
+    }
+
+    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
+        let props = ctx.props();
+        let mut positions = mem::take(&mut self.positions);
+        for entry in &mut self.entries {
+            if let Some(phase) = &entry.phase {
+                phase.restore_classes();
+            }
+            let pending = entry.pending.take();
+            // Entering children aren't moved, they start at their new position
+            let previous = positions
+                .remove(&entry.key)
+                .filter(|_| entry.phase.iter().all(|phase| phase.moving));
+            if pending.is_none() && previous.is_none() {
+                continue;
+            }
+            let element = element(&entry.node);
+
+            self.generation += 1;
+            let generation = self.generation;
+            let key = entry.key.clone();
+            let on_done = ctx.link().callback(move |_| TransitionDone {
+                key: Some(key.clone()),
+                generation,
+            });
+
+            match (pending, element) {
+                (Some(kind), Some(element)) => {
+                    entry.generation = generation;
+                    entry.phase = Some(Phase::start(
+                        element,
+                        &props.name,
+                        kind,
+                        props.timeout,
+                        on_done,
+                    ));
+                }
+                (Some(PhaseKind::Leave), None) => {
+                    entry.generation = generation;
+                    on_done.emit(());
+                }
+                (None, Some(element)) => {
+                    let (x, y) = previous.unwrap_or_default();
+                    let (new_x, new_y) = position(&element);
+                    let delta = (x - new_x, y - new_y);
+                    if delta != (0.0, 0.0) {
+                        entry.generation = generation;
+                        entry.phase =
+                            Phase::flip(element, &props.name, delta, props.timeout, on_done);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
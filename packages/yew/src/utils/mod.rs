@@ -3,7 +3,7 @@
 use std::marker::PhantomData;
 
 use anyhow::{anyhow, Error};
-use web_sys::{Document, Window};
+use web_sys::{Document, Node, Window};
 
 use yew::html::ChildrenRenderer;
 
@@ -17,6 +17,12 @@ pub fn document() -> Document {
     window().document().unwrap()
 }
 
+/// Returns the document that owns `node`, which differs from [`document`] for nodes inside an
+/// iframe.
+pub(crate) fn owner_document(node: &Node) -> Document {
+    node.owner_document().unwrap_or_else(document)
+}
+
 /// Returns the `host` for the current document. Useful for connecting to the server which serves
 /// the app.
 pub fn host() -> Result<String, Error> {
@@ -82,7 +82,7 @@ mod test {
     fn all_key_conversions() {
         html! {
             <key="string literal">
-                <img key={"String".to_owned()} />
+                <img key={"String".to_owned()} lint="allow" />
                 <p key={Rc::<str>::from("rc")}></p>
                 <key='a'>
                     <p key=11_usize></p>
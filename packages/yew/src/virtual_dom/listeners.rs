@@ -1,5 +1,6 @@
+use crate::app_handle::AppConfig;
 use std::{
-    cell::RefCell,
+    cell::{Cell, RefCell},
     collections::{HashMap, HashSet},
     ops::Deref,
     rc::Rc,
@@ -14,13 +15,10 @@ thread_local! {
     /// Key used to store listener id on element
     static LISTENER_ID_PROP: wasm_bindgen::JsValue = "__yew_listener_id".into();
 
-    /// Cached reference to the document body
-    static BODY: web_sys::HtmlElement = crate::utils::document().body().unwrap();
+    /// Whether events bubble in apps that don't configure it
+    static DEFAULT_EVENT_BUBBLING: Cell<bool> = const { Cell::new(true) };
 }
 
-/// Bubble events during delegation
-static mut BUBBLE_EVENTS: bool = true;
-
 /// Set, if events should bubble up the DOM tree, calling any matching callbacks.
 ///
 /// Bubbling is enabled by default. Disabling bubbling can lead to substantial improvements in event
@@ -30,11 +28,15 @@ static mut BUBBLE_EVENTS: bool = true;
 /// reasons. Calling `Event.stopPropagation()` or `Event.stopImmediatePropagation()` in the event
 /// handler has no effect.
 ///
-/// This function should be called before any component is mounted.
+/// This sets the default for apps that are mounted afterwards. Use
+/// [`AppBuilder::event_bubbling`](crate::AppBuilder::event_bubbling) to configure a single app.
 pub fn set_event_bubbling(bubble: bool) {
-    unsafe {
-        BUBBLE_EVENTS = bubble;
-    }
+    DEFAULT_EVENT_BUBBLING.with(|default| default.set(bubble));
+}
+
+/// Returns the value set with [`set_event_bubbling`].
+pub(crate) fn default_event_bubbling() -> bool {
+    DEFAULT_EVENT_BUBBLING.with(Cell::get)
 }
 
 /// The [Listener] trait is an universal implementation of an event listener
@@ -184,10 +186,11 @@ gen_listener_kinds! {
 }
 
 /// A list of event listeners
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub enum Listeners {
     /// No listeners registered or pending.
     /// Distinct from `Pending` with an empty slice to avoid an allocation.
+    #[default]
     None,
 
     /// Added to global registry by ID
@@ -199,10 +202,10 @@ pub enum Listeners {
 
 impl Listeners {
     /// Register listeners and return their handle ID
-    fn register(el: &Element, pending: &[Option<Rc<dyn Listener>>]) -> Self {
+    fn register(el: &Element, pending: &[Option<Rc<dyn Listener>>], app: &AppConfig) -> Self {
         Self::Registered(Registry::with(|reg| {
             let id = reg.set_listener_id(el);
-            reg.register(id, pending);
+            reg.register(id, pending, app);
             id
         }))
     }
@@ -213,28 +216,26 @@ impl Listeners {
             Registry::with(|r| r.unregister(id));
         }
     }
-}
-
-impl super::Apply for Listeners {
-    type Element = Element;
 
-    fn apply(&mut self, el: &Self::Element) {
+    /// Apply listeners to the element, delegating them to the root of `app`.
+    pub(super) fn apply(&mut self, el: &Element, app: &AppConfig) {
         if let Self::Pending(pending) = self {
-            *self = Self::register(el, pending);
+            *self = Self::register(el, pending, app);
         }
     }
 
-    fn apply_diff(&mut self, el: &Self::Element, ancestor: Self) {
+    /// Apply diff between [self] and `ancestor` to the element.
+    pub(super) fn apply_diff(&mut self, el: &Element, ancestor: Self, app: &AppConfig) {
         use Listeners::*;
 
         match (std::mem::take(self), ancestor) {
             (Pending(pending), Registered(id)) => {
                 // Reuse the ID
-                Registry::with(|reg| reg.patch(&id, &*pending));
+                Registry::with(|reg| reg.patch(&id, &*pending, app));
                 *self = Registered(id);
             }
             (Pending(pending), None) => {
-                *self = Self::register(el, &pending);
+                *self = Self::register(el, &pending, app);
             }
             (None, Registered(id)) => {
                 Registry::with(|reg| reg.unregister(&id));
@@ -257,16 +258,14 @@ impl PartialEq for Listeners {
 
                 Registry::with(|reg| match reg.by_id.get(registered_id) {
                     Some(reg) => {
+                        let reg = &reg.listeners;
                         if reg.len() != pending.len() {
                             return false;
                         }
 
                         pending.iter().filter_map(|l| l.as_ref()).all(|l| {
                             match reg.get(&EventDescriptor::from(l.deref())) {
-                                Some(reg) => reg.iter().any(|reg| {
-                                    #[allow(clippy::vtable_address_comparisons)]
-                                    Rc::ptr_eq(reg, l)
-                                }),
+                                Some(reg) => reg.iter().any(|reg| Rc::ptr_eq(reg, l)),
                                 None => false,
                             }
                         })
@@ -283,11 +282,7 @@ impl PartialEq for Listeners {
                     lhs.iter()
                         .zip(rhs.iter())
                         .all(|(lhs, rhs)| match (lhs, rhs) {
-                            (Some(lhs), Some(rhs)) =>
-                            {
-                                #[allow(clippy::vtable_address_comparisons)]
-                                Rc::ptr_eq(lhs, rhs)
-                            }
+                            (Some(lhs), Some(rhs)) => Rc::ptr_eq(lhs, rhs),
                             (None, None) => true,
                             _ => false,
                         })
@@ -307,12 +302,6 @@ impl Clone for Listeners {
     }
 }
 
-impl Default for Listeners {
-    fn default() -> Self {
-        Self::None
-    }
-}
-
 #[derive(Clone, Copy, Hash, Eq, PartialEq, Debug)]
 struct EventDescriptor {
     kind: ListenerKind,
@@ -333,48 +322,53 @@ impl From<&dyn Listener> for EventDescriptor {
 // Separate struct to DRY, while avoiding partial struct mutability.
 #[derive(Default, Debug)]
 struct GlobalHandlers {
-    /// Events with registered handlers that are possibly passive
-    handling: HashSet<EventDescriptor>,
+    /// Events with registered handlers that are possibly passive, by app ID
+    handling: HashSet<(u32, EventDescriptor)>,
 
     /// Keep track of all listeners to drop them on registry drop.
     /// The registry is never dropped in production.
     #[cfg(test)]
     #[allow(clippy::type_complexity)]
-    registered: Vec<(ListenerKind, Closure<dyn Fn(web_sys::Event)>)>,
+    registered: Vec<(
+        web_sys::EventTarget,
+        ListenerKind,
+        Closure<dyn Fn(web_sys::Event)>,
+    )>,
 }
 
 impl GlobalHandlers {
-    /// Ensure a descriptor has a global event handler assigned
-    fn ensure_handled(&mut self, desc: EventDescriptor) {
-        if !self.handling.contains(&desc) {
-            let cl = BODY.with(|body| {
-                let cl = Closure::wrap(
-                    Box::new(move |e: Event| Registry::handle(desc, e)) as Box<dyn Fn(Event)>
+    /// Ensure a descriptor has a global event handler assigned on the delegation root of `app`
+    fn ensure_handled(&mut self, desc: EventDescriptor, app: &AppConfig) {
+        if !self.handling.contains(&(app.id, desc)) {
+            let target = app.delegation_target();
+            let (app_id, bubble) = (app.id, app.event_bubbling);
+            let cl =
+                Closure::wrap(
+                    Box::new(move |e: Event| Registry::handle(app_id, bubble, desc, e))
+                        as Box<dyn Fn(Event)>,
                 );
-                AsRef::<web_sys::EventTarget>::as_ref(body)
-                    .add_event_listener_with_callback_and_add_event_listener_options(
-                        &desc.kind.as_ref()[2..],
-                        cl.as_ref().unchecked_ref(),
-                        &{
-                            let mut opts = web_sys::AddEventListenerOptions::new();
-                            if desc.passive {
-                                opts.passive(true);
-                            }
-                            opts
-                        },
-                    )
-                    .map_err(|e| format!("could not register global listener: {:?}", e))
-                    .unwrap();
-                cl
-            });
+            target
+                .add_event_listener_with_callback_and_add_event_listener_options(
+                    &desc.kind.as_ref()[2..],
+                    cl.as_ref().unchecked_ref(),
+                    &{
+                        let mut opts = web_sys::AddEventListenerOptions::new();
+                        if desc.passive {
+                            opts.passive(true);
+                        }
+                        opts
+                    },
+                )
+                .map_err(|e| format!("could not register global listener: {:?}", e))
+                .unwrap();
 
             // Never drop the closure as this event handler is static
             #[cfg(not(test))]
             cl.forget();
             #[cfg(test)]
-            self.registered.push((desc.kind, cl));
+            self.registered.push((target, desc.kind, cl));
 
-            self.handling.insert(desc);
+            self.handling.insert((app.id, desc));
         }
     }
 }
@@ -383,19 +377,26 @@ impl GlobalHandlers {
 #[cfg(test)]
 impl Drop for GlobalHandlers {
     fn drop(&mut self) {
-        BODY.with(|body| {
-            for (kind, cl) in std::mem::take(&mut self.registered) {
-                AsRef::<web_sys::EventTarget>::as_ref(body)
-                    .remove_event_listener_with_callback(
-                        &kind.as_ref()[2..],
-                        cl.as_ref().unchecked_ref(),
-                    )
-                    .unwrap();
-            }
-        });
+        for (target, kind, cl) in std::mem::take(&mut self.registered) {
+            target
+                .remove_event_listener_with_callback(
+                    &kind.as_ref()[2..],
+                    cl.as_ref().unchecked_ref(),
+                )
+                .unwrap();
+        }
     }
 }
 
+/// Listeners registered for an element
+#[derive(Debug)]
+struct Registration {
+    /// ID of the app the element belongs to
+    app_id: u32,
+
+    listeners: HashMap<EventDescriptor, Vec<Rc<dyn Listener>>>,
+}
+
 /// Global multiplexing event handler registry
 #[derive(Default, Debug)]
 struct Registry {
@@ -406,7 +407,7 @@ struct Registry {
     global: GlobalHandlers,
 
     /// Contains all registered event listeners by listener ID
-    by_id: HashMap<u32, HashMap<EventDescriptor, Vec<Rc<dyn Listener>>>>,
+    by_id: HashMap<u32, Registration>,
 }
 
 impl Registry {
@@ -417,29 +418,35 @@ impl Registry {
     }
 
     /// Register all passed listeners under ID
-    fn register(&mut self, id: u32, listeners: &[Option<Rc<dyn Listener>>]) {
+    fn register(&mut self, id: u32, listeners: &[Option<Rc<dyn Listener>>], app: &AppConfig) {
         let mut by_desc =
             HashMap::<EventDescriptor, Vec<Rc<dyn Listener>>>::with_capacity(listeners.len());
         for l in listeners.iter().filter_map(|l| l.as_ref()).cloned() {
             let desc = EventDescriptor::from(l.deref());
-            self.global.ensure_handled(desc);
+            self.global.ensure_handled(desc, app);
             by_desc.entry(desc).or_default().push(l);
         }
-        self.by_id.insert(id, by_desc);
+        self.by_id.insert(
+            id,
+            Registration {
+                app_id: app.id,
+                listeners: by_desc,
+            },
+        );
     }
 
     /// Patch an already registered set of handlers
-    fn patch(&mut self, id: &u32, listeners: &[Option<Rc<dyn Listener>>]) {
-        if let Some(by_desc) = self.by_id.get_mut(id) {
+    fn patch(&mut self, id: &u32, listeners: &[Option<Rc<dyn Listener>>], app: &AppConfig) {
+        if let Some(reg) = self.by_id.get_mut(id) {
             // Keeping empty vectors is fine. Those don't do much and should happen rarely.
-            for v in by_desc.values_mut() {
+            for v in reg.listeners.values_mut() {
                 v.clear()
             }
 
             for l in listeners.iter().filter_map(|l| l.as_ref()).cloned() {
                 let desc = EventDescriptor::from(l.deref());
-                self.global.ensure_handled(desc);
-                by_desc.entry(desc).or_default().push(l);
+                self.global.ensure_handled(desc, app);
+                reg.listeners.entry(desc).or_default().push(l);
             }
         }
     }
@@ -463,36 +470,44 @@ impl Registry {
         id
     }
 
-    /// Handle a global event firing
-    fn handle(desc: EventDescriptor, event: Event) {
-        let target = match event
-            .target()
-            .map(|el| el.dyn_into::<web_sys::Element>().ok())
-            .flatten()
-        {
-            Some(el) => el,
-            None => return,
+    /// Handle a global event firing for the app with `app_id`
+    fn handle(app_id: u32, bubble: bool, desc: EventDescriptor, event: Event) {
+        // Elements inside an iframe aren't instances of this realm's `Element` class, so the node
+        // type is checked instead
+        let target = match event.target() {
+            Some(target)
+                if target.unchecked_ref::<web_sys::Node>().node_type()
+                    == web_sys::Node::ELEMENT_NODE =>
+            {
+                target.unchecked_into::<web_sys::Element>()
+            }
+            _ => return,
         };
 
-        Self::run_handlers(desc, event, target);
+        Self::run_handlers(app_id, bubble, desc, event, target);
     }
 
-    fn run_handlers(desc: EventDescriptor, event: Event, target: web_sys::Element) {
+    fn run_handlers(
+        app_id: u32,
+        bubble: bool,
+        desc: EventDescriptor,
+        event: Event,
+        target: web_sys::Element,
+    ) {
         let run_handler = |el: &web_sys::Element| {
             if let Some(l) = LISTENER_ID_PROP
                 .with(|prop| js_sys::Reflect::get(el, prop).ok())
-                .map(|v| v.dyn_into().ok())
-                .flatten()
-                .map(|num: js_sys::Number| {
+                .and_then(|v| v.dyn_into().ok())
+                .and_then(|num: js_sys::Number| {
                     Registry::with(|r| {
                         r.by_id
                             .get(&(num.value_of() as u32))
-                            .map(|s| s.get(&desc))
-                            .flatten()
+                            // Elements of other apps are handled by their own global handlers
+                            .filter(|reg| reg.app_id == app_id)
+                            .and_then(|reg| reg.listeners.get(&desc))
                             .cloned()
                     })
                 })
-                .flatten()
             {
                 for l in l {
                     l.handle(event.clone());
@@ -502,7 +517,7 @@ impl Registry {
 
         run_handler(&target);
 
-        if unsafe { BUBBLE_EVENTS } {
+        if bubble {
             let mut el = target;
             loop {
                 el = match el.parent_element() {
@@ -525,7 +540,10 @@ mod tests {
     use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
     wasm_bindgen_test_configure!(run_in_browser);
 
-    use crate::{html, html::TargetCast, utils::document, AppHandle, Component, Context, Html};
+    use crate::{
+        html, html::TargetCast, utils::document, App, AppBuilder, AppHandle, Component, Context,
+        Html,
+    };
     use wasm_bindgen::JsCast;
     use wasm_bindgen_futures::JsFuture;
 
@@ -625,6 +643,16 @@ mod tests {
     }
 
     fn init<M>(tag: &str) -> (AppHandle<Comp<M>>, web_sys::HtmlElement)
+    where
+        M: Mixin,
+    {
+        init_with(tag, |builder| builder)
+    }
+
+    fn init_with<M>(
+        tag: &str,
+        configure: impl FnOnce(AppBuilder<Comp<M>>) -> AppBuilder<Comp<M>>,
+    ) -> (AppHandle<Comp<M>>, web_sys::HtmlElement)
     where
         M: Mixin,
     {
@@ -636,7 +664,7 @@ mod tests {
 
         let root = document().create_element("div").unwrap();
         document().body().unwrap().append_child(&root).unwrap();
-        let app = crate::start_app_in_element::<Comp<M>>(root);
+        let app = configure(App::<Comp<M>>::builder().mount_point(root)).mount();
 
         (app, get_el_by_tag(tag))
     }
@@ -706,36 +734,36 @@ mod tests {
         assert_after_click!(2);
     }
 
-    #[test]
-    fn bubbling() {
-        struct Bubbling;
+    struct Bubbling;
 
-        impl Mixin for Bubbling {
-            fn view<C>(ctx: &Context<C>, state: &State) -> Html
-            where
-                C: Component<Message = Message>,
-            {
-                if state.stop_listening {
-                    html! {
-                        <div>
-                            <a>
-                                {state.clicked}
-                            </a>
-                        </div>
-                    }
-                } else {
-                    let cb = ctx.link().callback(|_| Message::Click);
-                    html! {
-                        <div onclick={cb.clone()}>
-                            <a onclick={cb}>
-                                {state.clicked}
-                            </a>
-                        </div>
-                    }
+    impl Mixin for Bubbling {
+        fn view<C>(ctx: &Context<C>, state: &State) -> Html
+        where
+            C: Component<Message = Message>,
+        {
+            if state.stop_listening {
+                html! {
+                    <div>
+                        <a>
+                            {state.clicked}
+                        </a>
+                    </div>
+                }
+            } else {
+                let cb = ctx.link().callback(|_| Message::Click);
+                html! {
+                    <div onclick={cb.clone()} lint="allow">
+                        <a onclick={cb}>
+                            {state.clicked}
+                        </a>
+                    </div>
                 }
             }
         }
+    }
 
+    #[test]
+    fn bubbling() {
         let (link, el) = init::<Bubbling>("a");
 
         assert_count(&el, 0);
@@ -751,6 +779,37 @@ mod tests {
         assert_count(&el, 4);
     }
 
+    #[test]
+    fn bubbling_disabled_per_app() {
+        let (_link, el) = init_with::<Bubbling>("a", |builder| builder.event_bubbling(false));
+
+        assert_count(&el, 0);
+
+        // Only the listener of the target element runs
+        el.click();
+        assert_count(&el, 1);
+
+        el.click();
+        assert_count(&el, 2);
+    }
+
+    #[test]
+    fn delegation_root() {
+        let root = document().create_element("section").unwrap();
+        document().body().unwrap().append_child(&root).unwrap();
+        let (_link, el) =
+            init_with::<Bubbling>("a", |builder| builder.delegation_root(root.clone()));
+        // Events don't reach the delegation root while it doesn't contain the app
+        el.click();
+        assert_count(&el, 0);
+
+        root.append_child(&el.parent_element().unwrap().parent_element().unwrap())
+            .unwrap();
+        el.click();
+        assert_count(&el, 2);
+        root.remove();
+    }
+
     fn test_input_listener<E>(make_event: impl Fn() -> E)
     where
         E: JsCast + std::fmt::Debug,
@@ -5,6 +5,8 @@ pub mod key;
 #[doc(hidden)]
 pub mod listeners;
 #[doc(hidden)]
+pub mod spread;
+#[doc(hidden)]
 pub mod vcomp;
 #[doc(hidden)]
 pub mod vlist;
@@ -17,7 +19,7 @@ pub mod vtext;
 
 use crate::html::{AnyScope, NodeRef};
 use indexmap::IndexMap;
-use std::{borrow::Cow, collections::HashMap, hint::unreachable_unchecked, iter};
+use std::{borrow::Cow, collections::HashMap, hint::unreachable_unchecked, iter, rc::Rc};
 use web_sys::{Element, Node};
 
 #[doc(inline)]
@@ -25,6 +27,8 @@ pub use self::key::Key;
 #[doc(inline)]
 pub use self::listeners::*;
 #[doc(inline)]
+pub use self::spread::Spread;
+#[doc(inline)]
 pub use self::vcomp::{VChild, VComp};
 #[doc(inline)]
 pub use self::vlist::VList;
@@ -330,6 +334,33 @@ impl Default for Attributes {
     }
 }
 
+/// Identifies the nodes which are clones of the same static subtree hoisted by `html!`.
+///
+/// Those nodes are identical, so diffing one against another can keep the ancestor as it is.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Hoisted(Option<Rc<()>>);
+
+impl Hoisted {
+    fn new() -> Self {
+        Self(Some(Rc::new(())))
+    }
+
+    /// Returns `true` if both nodes are clones of the same static subtree.
+    fn same_subtree(&self, other: &Self) -> bool {
+        match (&self.0, &other.0) {
+            (Some(this), Some(other)) => Rc::ptr_eq(this, other),
+            _ => false,
+        }
+    }
+}
+
+// The marker doesn't affect the equality of nodes
+impl PartialEq for Hoisted {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
 // TODO(#938): What about implementing `VDiff` for `Element`?
 // It would make it possible to include ANY element into the tree.
 // `Ace` editor embedding for example?
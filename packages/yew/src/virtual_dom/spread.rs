@@ -0,0 +1,96 @@
+//! This module contains the implementation of [Spread].
+
+use super::{AttrValue, Attributes, Listener};
+use indexmap::IndexMap;
+use std::rc::Rc;
+
+/// Attributes and listeners which are spread onto an element with `<tag ..spread>`.
+///
+/// Attributes and listeners which are written out on the element take precedence over the ones
+/// in the spread. An optional attribute which is set to `None` doesn't override the spread.
+///
+/// # Example
+///
+/// ```
+/// use yew::html::onclick;
+/// use yew::virtual_dom::Spread;
+/// use yew::{html, Callback, Html};
+///
+/// fn close_button(spread: Spread) -> Html {
+///     html! {
+///         <button class="close" aria-label="Close" ..spread>{ "×" }</button>
+///     }
+/// }
+///
+/// close_button(
+///     Spread::new()
+///         .attribute("aria-label", "Close the dialog")
+///         .listener(onclick::Wrapper::new(Callback::from(|_| ()))),
+/// );
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Spread {
+    pub(crate) attributes: IndexMap<&'static str, AttrValue>,
+    pub(crate) listeners: Vec<Rc<dyn Listener>>,
+}
+
+impl Spread {
+    /// Creates an empty [Spread].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an attribute, replacing the previous value if there is one.
+    pub fn attribute(mut self, key: &'static str, value: impl Into<AttrValue>) -> Self {
+        self.attributes.insert(key, value.into());
+        self
+    }
+
+    /// Adds an event listener, replacing the previous listener for the same event if there is
+    /// one.
+    pub fn listener(mut self, listener: impl Listener + 'static) -> Self {
+        self.insert_listener(Rc::new(listener));
+        self
+    }
+
+    pub(crate) fn insert_listener(&mut self, listener: Rc<dyn Listener>) {
+        let kind = listener.kind();
+        self.listeners.retain(|other| other.kind() != kind);
+        self.listeners.push(listener);
+    }
+
+    /// Returns the attributes of the spread.
+    pub fn attributes(&self) -> &IndexMap<&'static str, AttrValue> {
+        &self.attributes
+    }
+}
+
+impl PartialEq for Spread {
+    fn eq(&self, other: &Self) -> bool {
+        self.attributes == other.attributes
+            && self.listeners.len() == other.listeners.len()
+            && self
+                .listeners
+                .iter()
+                .zip(other.listeners.iter())
+                .all(|(lhs, rhs)| Rc::ptr_eq(lhs, rhs))
+    }
+}
+
+impl From<Attributes> for Spread {
+    fn from(mut attributes: Attributes) -> Self {
+        Self {
+            attributes: std::mem::take(attributes.get_mut_index_map()),
+            listeners: Vec::new(),
+        }
+    }
+}
+
+impl From<IndexMap<&'static str, AttrValue>> for Spread {
+    fn from(attributes: IndexMap<&'static str, AttrValue>) -> Self {
+        Self {
+            attributes,
+            listeners: Vec::new(),
+        }
+    }
+}
@@ -103,6 +103,12 @@ impl VComp {
     pub(crate) fn root_vnode(&self) -> Option<impl Deref<Target = VNode> + '_> {
         self.scope.as_ref().and_then(|scope| scope.root_vnode())
     }
+
+    pub(crate) fn set_parent(&self, parent: &Element) {
+        if let Some(scope) = &self.scope {
+            scope.set_parent(parent);
+        }
+    }
 }
 
 trait Mountable {
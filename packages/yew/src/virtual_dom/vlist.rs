@@ -1,5 +1,5 @@
 //! This module contains fragments implementation.
-use super::{Key, VDiff, VNode, VText};
+use super::{Hoisted, Key, VDiff, VNode, VText};
 use crate::html::{AnyScope, NodeRef};
 use std::collections::HashMap;
 use std::ops::{Deref, DerefMut};
@@ -15,6 +15,9 @@ pub struct VList {
     fully_keyed: bool,
 
     pub key: Option<Key>,
+
+    /// Marks the clones of a static subtree hoisted by `html!`.
+    hoisted: Hoisted,
 }
 
 impl Default for VList {
@@ -23,6 +26,7 @@ impl Default for VList {
             children: Default::default(),
             key: None,
             fully_keyed: true,
+            hoisted: Default::default(),
         }
     }
 }
@@ -78,6 +82,22 @@ impl VList {
             fully_keyed: children.iter().all(|ch| ch.has_key()),
             children,
             key,
+            hoisted: Default::default(),
+        }
+    }
+
+    /// Marks this [VList] as a static subtree hoisted by `html!`.
+    pub(crate) fn hoist(&mut self) {
+        self.hoisted = Hoisted::new();
+    }
+
+    /// Clones a static subtree hoisted by `html!`, see [VNode::__clone_hoisted()].
+    pub(crate) fn clone_hoisted(&self) -> Self {
+        VList {
+            children: self.children.iter().map(VNode::__clone_hoisted).collect(),
+            fully_keyed: self.fully_keyed,
+            key: self.key.clone(),
+            hoisted: self.hoisted.clone(),
         }
     }
 
@@ -329,6 +349,15 @@ impl VDiff for VList {
         // (self.children). For the right ones, we will look at the ancestor,
         // i.e. the current DOM list element that we want to replace with self.
 
+        // Clones of the same static subtree are identical, so the ancestor can be kept as it is
+        let ancestor = match ancestor {
+            Some(VNode::VList(ancestor)) if self.hoisted.same_subtree(&ancestor.hoisted) => {
+                *self = ancestor;
+                return NodeRef::new(self.children[0].first_node());
+            }
+            ancestor => ancestor,
+        };
+
         if self.children.is_empty() {
             // Without a placeholder the next element becomes first
             // and corrupts the order of rendering
@@ -365,6 +394,72 @@ impl VDiff for VList {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html;
+    use crate::utils::document;
+    use web_sys::Node;
+
+    #[cfg(feature = "wasm_test")]
+    use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+    #[cfg(feature = "wasm_test")]
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn assert_vlist(node: &VNode) -> &VList {
+        if let VNode::VList(vlist) = node {
+            return vlist;
+        }
+        panic!("should be vlist");
+    }
+
+    /// Renders `render` twice, diffing the second result against the first one.
+    fn apply_twice(render: impl Fn() -> VNode) -> (Element, VNode, Vec<Node>, NodeRef) {
+        let scope = AnyScope::test();
+        let parent = document().create_element("div").unwrap();
+        document().body().unwrap().append_child(&parent).unwrap();
+
+        let mut list_a = render();
+        list_a.apply(&scope, &parent, NodeRef::default(), None);
+        let nodes_a = assert_vlist(&list_a)
+            .children
+            .iter()
+            .map(VNode::first_node)
+            .collect();
+
+        let mut list_b = render();
+        let node_ref = list_b.apply(&scope, &parent, NodeRef::default(), Some(list_a));
+        (parent, list_b, nodes_a, node_ref)
+    }
+
+    #[test]
+    fn hoisted_fragments_keep_the_ancestor() {
+        let (parent, list, nodes_a, node_ref) =
+            apply_twice(|| html! { <><span>{ "a" }</span>{ "b" }</> });
+
+        let nodes_b: Vec<Node> = assert_vlist(&list)
+            .children
+            .iter()
+            .map(VNode::first_node)
+            .collect();
+        assert_eq!(nodes_a, nodes_b, "VList should have kept its children");
+        assert_eq!(node_ref.get(), Some(nodes_b[0].clone()));
+        assert_eq!(parent.inner_html(), "<span>a</span>b");
+    }
+
+    #[test]
+    fn hoisted_empty_fragments_keep_the_placeholder() {
+        let (parent, list, nodes_a, node_ref) = apply_twice(|| html! { <></> });
+
+        let list = assert_vlist(&list);
+        assert_eq!(list.children.len(), 1);
+        assert_eq!(nodes_a, vec![list.children[0].first_node()]);
+        assert_eq!(node_ref.get(), Some(nodes_a[0].clone()));
+        assert_eq!(parent.inner_html(), "");
+    }
+}
+
 #[cfg(test)]
 mod layout_tests {
     extern crate self as yew;
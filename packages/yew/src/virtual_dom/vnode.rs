@@ -45,6 +45,31 @@ impl VNode {
         }
     }
 
+    /// Marks a static subtree, which `html!` builds once and clones on every render.
+    ///
+    /// Diffing two clones of the same subtree keeps the ancestor without comparing them.
+    #[doc(hidden)]
+    pub fn __hoist(mut self) -> Self {
+        match &mut self {
+            VNode::VTag(vtag) => vtag.hoist(),
+            VNode::VList(vlist) => vlist.hoist(),
+            _ => {}
+        }
+        self
+    }
+
+    /// Clones a static subtree marked by [VNode::__hoist()].
+    ///
+    /// Every element of the clone gets its own [NodeRef].
+    #[doc(hidden)]
+    pub fn __clone_hoisted(&self) -> Self {
+        match self {
+            VNode::VTag(vtag) => VNode::VTag(Box::new(vtag.clone_hoisted())),
+            VNode::VList(vlist) => VNode::VList(vlist.clone_hoisted()),
+            node => node.clone(),
+        }
+    }
+
     /// Returns the first DOM node that is used to designate the position of the virtual DOM node.
     pub(crate) fn first_node(&self) -> Node {
         match self {
@@ -63,6 +88,20 @@ impl VNode {
         }
     }
 
+    /// Updates the parent element of the components that render directly into `parent` after
+    /// the nodes of this [VNode] were moved into it.
+    pub(crate) fn set_parent(&self, parent: &Element) {
+        match self {
+            VNode::VList(vlist) => {
+                for node in vlist.iter() {
+                    node.set_parent(parent);
+                }
+            }
+            VNode::VComp(vcomp) => vcomp.set_parent(parent),
+            VNode::VTag(_) | VNode::VText(_) | VNode::VRef(_) => {}
+        }
+    }
+
     pub(crate) fn move_before(&self, parent: &Element, next_sibling: &Option<Node>) {
         match self {
             VNode::VList(vlist) => {
@@ -1,8 +1,10 @@
 //! This module contains the implementation of a virtual element node [VTag].
 
-use super::{Apply, AttrValue, Attributes, Key, Listener, Listeners, VDiff, VList, VNode};
+use super::{
+    Apply, AttrValue, Attributes, Hoisted, Key, Listener, Listeners, Spread, VDiff, VList, VNode,
+};
 use crate::html::{AnyScope, IntoPropValue, NodeRef};
-use crate::utils::document;
+use crate::utils::owner_document;
 use gloo::console;
 use std::borrow::Cow;
 use std::cmp::PartialEq;
@@ -165,6 +167,9 @@ pub struct VTag {
     pub attributes: Attributes,
 
     pub key: Option<Key>,
+
+    /// Marks the clones of a static subtree hoisted by `html!`.
+    hoisted: Hoisted,
 }
 
 impl Clone for VTag {
@@ -176,6 +181,7 @@ impl Clone for VTag {
             attributes: self.attributes.clone(),
             node_ref: self.node_ref.clone(),
             key: self.key.clone(),
+            hoisted: self.hoisted.clone(),
         }
     }
 }
@@ -307,6 +313,34 @@ impl VTag {
             listeners,
             node_ref,
             key,
+            hoisted: Default::default(),
+        }
+    }
+
+    /// Marks this [VTag] as a static subtree hoisted by `html!`.
+    pub(crate) fn hoist(&mut self) {
+        self.hoisted = Hoisted::new();
+    }
+
+    /// Clones a static subtree hoisted by `html!`.
+    ///
+    /// Unlike [VTag::clone()], every element gets its own [NodeRef] because the [NodeRef]s are set
+    /// to the elements when the clones are mounted.
+    pub(crate) fn clone_hoisted(&self) -> Self {
+        VTag {
+            inner: match &self.inner {
+                VTagInner::Other { tag, children } => VTagInner::Other {
+                    tag: tag.clone(),
+                    children: children.clone_hoisted(),
+                },
+                inner => inner.clone(),
+            },
+            reference: None,
+            listeners: self.listeners.clone(),
+            attributes: self.attributes.clone(),
+            node_ref: NodeRef::default(),
+            key: self.key.clone(),
+            hoisted: self.hoisted.clone(),
         }
     }
 
@@ -435,6 +469,41 @@ impl VTag {
             .insert(key, value.into_prop_value());
     }
 
+    #[doc(hidden)]
+    pub fn __macro_spread(mut self, spread: impl Into<Spread>) -> Self {
+        let Spread {
+            mut attributes,
+            listeners,
+        } = spread.into();
+
+        // attributes and listeners of the element take precedence over the spread
+        for (key, value) in std::mem::take(self.attributes.get_mut_index_map()) {
+            attributes.insert(key, value);
+        }
+        self.attributes = Attributes::IndexMap(attributes);
+
+        let own_listeners = match std::mem::take(&mut self.listeners) {
+            Listeners::Pending(listeners) => listeners.into_vec(),
+            _ => Vec::new(),
+        };
+        let mut merged: Vec<Option<Rc<dyn Listener>>> = listeners
+            .into_iter()
+            .filter(|listener| {
+                !own_listeners
+                    .iter()
+                    .flatten()
+                    .any(|own| own.kind() == listener.kind())
+            })
+            .map(Some)
+            .collect();
+        merged.extend(own_listeners);
+        if !merged.is_empty() {
+            self.listeners = Listeners::Pending(merged.into_boxed_slice());
+        }
+
+        self
+    }
+
     /// Set event listeners on the [VTag]'s  [Element]
     pub fn set_listener(&mut self, listeners: Box<[Option<Rc<dyn Listener>>]>) {
         self.listeners = Listeners::Pending(listeners);
@@ -442,17 +511,13 @@ impl VTag {
 
     fn create_element(&self, parent: &Element) -> Element {
         let tag = self.tag();
-        if tag == "svg"
-            || parent
-                .namespace_uri()
-                .map_or(false, |ns| ns == SVG_NAMESPACE)
-        {
+        if tag == "svg" || parent.namespace_uri().as_deref() == Some(SVG_NAMESPACE) {
             let namespace = Some(SVG_NAMESPACE);
-            document()
+            owner_document(parent)
                 .create_element_ns(namespace, tag)
                 .expect("can't create namespaced element for vtag")
         } else {
-            document()
+            owner_document(parent)
                 .create_element(tag)
                 .expect("can't create element for vtag")
         }
@@ -488,6 +553,15 @@ impl VDiff for VTag {
         next_sibling: NodeRef,
         ancestor: Option<VNode>,
     ) -> NodeRef {
+        // Clones of the same static subtree are identical, so the ancestor can be kept as it is
+        let ancestor = match ancestor {
+            Some(VNode::VTag(ancestor)) if self.hoisted.same_subtree(&ancestor.hoisted) => {
+                *self = *ancestor;
+                return self.node_ref.clone();
+            }
+            ancestor => ancestor,
+        };
+
         // This kind of branching patching routine reduces branch predictor misses and the need to
         // unpack the enums (including `Option`s) all the time, resulting in a more streamlined
         // patching flow
@@ -536,7 +610,7 @@ impl VDiff for VTag {
         match ancestor_tag {
             None => {
                 self.attributes.apply(&el);
-                self.listeners.apply(&el);
+                self.listeners.apply(&el, parent_scope.config());
 
                 match &mut self.inner {
                     VTagInner::Input(f) => {
@@ -554,7 +628,8 @@ impl VDiff for VTag {
             }
             Some(ancestor) => {
                 self.attributes.apply_diff(&el, ancestor.attributes);
-                self.listeners.apply_diff(&el, ancestor.listeners);
+                self.listeners
+                    .apply_diff(&el, ancestor.listeners, parent_scope.config());
 
                 match (&mut self.inner, ancestor.inner) {
                     (VTagInner::Input(new), VTagInner::Input(old)) => {
@@ -612,6 +687,7 @@ impl PartialEq for VTag {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::utils::document;
     use crate::{html, Html};
 
     #[cfg(feature = "wasm_test")]
@@ -663,15 +739,15 @@ mod tests {
     #[test]
     fn it_compares_attributes_static() {
         let a = html! {
-            <div a="test"></div>
+            <div a="test" lint="allow"></div>
         };
 
         let b = html! {
-            <div a="test"></div>
+            <div a="test" lint="allow"></div>
         };
 
         let c = html! {
-            <div a="fail"></div>
+            <div a="fail" lint="allow"></div>
         };
 
         assert_eq!(a, b);
@@ -681,15 +757,15 @@ mod tests {
     #[test]
     fn it_compares_attributes_dynamic() {
         let a = html! {
-            <div a={"test".to_owned()}></div>
+            <div a={"test".to_owned()} lint="allow"></div>
         };
 
         let b = html! {
-            <div a={"test".to_owned()}></div>
+            <div a={"test".to_owned()} lint="allow"></div>
         };
 
         let c = html! {
-            <div a={"fail".to_owned()}></div>
+            <div a={"fail".to_owned()} lint="allow"></div>
         };
 
         assert_eq!(a, b);
@@ -890,7 +966,7 @@ mod tests {
                         aria-controls="collapseExample">
                     { "Button with data-target" }
                 </button>
-                <div own-attribute-with-multiple-parts="works" />
+                <div own-attribute-with-multiple-parts="works" lint="allow" />
             </p>
         };
         if let VNode::VTag(vtag) = a {
@@ -1088,6 +1164,70 @@ mod tests {
         assert_eq!(vtag.tag(), "textarea");
     }
 
+    #[test]
+    fn attributes_stay_static_without_spread() {
+        let mut el = html! { <div id="static" /> };
+        let vtag = assert_vtag_mut(&mut el);
+        assert!(matches!(vtag.attributes, Attributes::Static(_)));
+    }
+
+    #[test]
+    fn explicit_attributes_take_precedence_over_spread() {
+        let spread = Spread::new()
+            .attribute("id", "spread")
+            .attribute("title", "spread");
+        let mut el = html! { <div id="explicit" class="explicit" ..spread /> };
+        let vtag = assert_vtag_mut(&mut el);
+        let attributes: Vec<_> = vtag.attributes.iter().collect();
+        assert_eq!(
+            attributes,
+            vec![
+                ("id", "explicit"),
+                ("title", "spread"),
+                ("class", "explicit")
+            ]
+        );
+
+        // unset optional attributes don't override the spread
+        let mut el = html! {
+            <div title={None::<AttrValue>} ..Spread::new().attribute("title", "spread") />
+        };
+        let vtag = assert_vtag_mut(&mut el);
+        let attributes: Vec<_> = vtag.attributes.iter().collect();
+        assert_eq!(attributes, vec![("title", "spread")]);
+
+        let mut el =
+            html! { <@{"span"} id="explicit" ..Spread::new().attribute("id", "spread") /> };
+        let vtag = assert_vtag_mut(&mut el);
+        let attributes: Vec<_> = vtag.attributes.iter().collect();
+        assert_eq!(attributes, vec![("id", "explicit")]);
+    }
+
+    #[test]
+    fn explicit_listeners_take_precedence_over_spread() {
+        use crate::html::{onblur, onclick};
+        use crate::virtual_dom::ListenerKind;
+        use crate::Callback;
+
+        let spread = Spread::new()
+            .listener(onclick::Wrapper::new(Callback::from(|_| ())))
+            .listener(onblur::Wrapper::new(Callback::from(|_| ())));
+        let spread_onclick = spread.listeners[0].clone();
+        let mut el = html! { <button onclick={Callback::from(|_| ())} ..spread /> };
+        let vtag = assert_vtag_mut(&mut el);
+        let listeners = match &vtag.listeners {
+            Listeners::Pending(listeners) => listeners,
+            _ => panic!("expected pending listeners"),
+        };
+        let kinds: Vec<_> = listeners.iter().flatten().map(|l| l.kind()).collect();
+        assert_eq!(kinds, vec![ListenerKind::onblur, ListenerKind::onclick]);
+        let spread_onclick_used = listeners
+            .iter()
+            .flatten()
+            .any(|listener| Rc::ptr_eq(listener, &spread_onclick));
+        assert!(!spread_onclick_used);
+    }
+
     #[test]
     fn reset_node_ref() {
         let scope = test_scope();
@@ -1130,6 +1270,70 @@ mod tests {
             "node_ref_a should have been reset when the element was reused."
         );
     }
+
+    #[test]
+    fn static_subtrees_are_hoisted() {
+        let render = || html! { <div class="static"><span>{ "text" }</span></div> };
+        let (a, b) = (render(), render());
+        assert_eq!(a, b);
+        let (a, b) = (assert_vtag(&a), assert_vtag(&b));
+        assert!(a.hoisted.same_subtree(&b.hoisted));
+        assert_ne!(a.node_ref, b.node_ref);
+
+        let render = |class: &str| html! { <div class={class.to_owned()} /> };
+        let (a, b) = (render("dynamic"), render("dynamic"));
+        assert!(!assert_vtag(&a)
+            .hoisted
+            .same_subtree(&assert_vtag(&b).hoisted));
+    }
+
+    #[test]
+    fn static_children_of_dynamic_elements_are_hoisted() {
+        let render = |class: &str| {
+            html! {
+                <div class={class.to_owned()}>
+                    <span>{ "static" }</span>
+                    <p class="static"><b>{ "nested" }</b></p>
+                    <span>{ class.to_owned() }</span>
+                </div>
+            }
+        };
+        let (a, b) = (render("dynamic"), render("dynamic"));
+        let (a, b) = (assert_vtag(&a), assert_vtag(&b));
+        assert!(!a.hoisted.same_subtree(&b.hoisted));
+
+        let hoisted = a
+            .children()
+            .iter()
+            .zip(b.children().iter())
+            .map(|(a, b)| assert_vtag(a).hoisted.same_subtree(&assert_vtag(b).hoisted))
+            .collect::<Vec<_>>();
+        assert_eq!(hoisted, vec![true, true, false]);
+    }
+
+    #[test]
+    fn hoisted_subtrees_keep_the_ancestor() {
+        let scope = test_scope();
+        let parent = document().create_element("div").unwrap();
+        document().body().unwrap().append_child(&parent).unwrap();
+
+        let render = || html! { <div class="static"><span>{ "text" }</span></div> };
+        let mut elem_a = render();
+        elem_a.apply(&scope, &parent, NodeRef::default(), None);
+        let node_a = assert_vtag(&elem_a).reference().cloned().unwrap();
+        let child_a = node_a.first_child().unwrap();
+
+        let mut elem_b = render();
+        elem_b.apply(&scope, &parent, NodeRef::default(), Some(elem_a));
+        let node_b = assert_vtag(&elem_b).reference().cloned().unwrap();
+
+        assert_eq!(node_a, node_b, "VTag should have kept the element");
+        assert_eq!(Some(child_a), node_b.first_child());
+        assert_eq!(
+            parent.inner_html(),
+            "<div class=\"static\"><span>text</span></div>"
+        );
+    }
 }
 
 #[cfg(test)]
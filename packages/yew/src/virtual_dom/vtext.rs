@@ -2,7 +2,7 @@
 
 use super::{AttrValue, VDiff, VNode};
 use crate::html::{AnyScope, NodeRef};
-use crate::utils::document;
+use crate::utils::owner_document;
 use gloo::console;
 use std::cmp::PartialEq;
 use web_sys::{Element, Text as TextNode};
@@ -79,7 +79,7 @@ impl VDiff for VText {
             ancestor.detach(parent);
         }
 
-        let text_node = document().create_text_node(&self.text);
+        let text_node = owner_document(parent).create_text_node(&self.text);
         super::insert_node(&text_node, parent, next_sibling.get().as_ref());
         self.reference = Some(text_node.clone());
         NodeRef::new(text_node.into())
@@ -0,0 +1,437 @@
+//! This module contains a typed client for
+//! [WebSockets](https://developer.mozilla.org/en-US/docs/Web/API/WebSocket).
+//!
+//! Messages are encoded and decoded with a [`Format`], JSON being the default. The connection is
+//! re-established with an exponential backoff when it's lost, and messages sent while the
+//! socket is disconnected are buffered until it's open again.
+
+use crate::callback::Callback;
+use crate::format::{Format, FormatError, Json};
+use crate::scheduler::Shared;
+use gloo::timers::callback::Timeout;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::fmt;
+use std::marker::PhantomData;
+use std::rc::Rc;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+use web_sys::{BinaryType, MessageEvent, WebSocket};
+
+/// The status of a WebSocket connection.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WebSocketStatus {
+    /// The connection is being established.
+    Connecting,
+    /// The connection is open and messages are sent right away.
+    Open,
+    /// The connection was closed.
+    Closed,
+    /// The connection failed or was closed because of an error.
+    Error,
+    /// The connection couldn't be attempted, for example because the URL is invalid. It's not
+    /// retried.
+    Failed,
+}
+
+/// Controls how a lost connection is re-established.
+///
+/// The delay before the n-th reconnection attempt is `initial_delay * multiplier^n`
+/// milliseconds, capped at `max_delay`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReconnectPolicy {
+    /// Delay before the first reconnection attempt, in milliseconds.
+    pub initial_delay: u32,
+    /// Upper bound for the delay between attempts, in milliseconds.
+    pub max_delay: u32,
+    /// Factor the delay is multiplied by after every failed attempt.
+    pub multiplier: f64,
+    /// Number of consecutive attempts after which the client gives up, if any.
+    pub max_attempts: Option<u32>,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            initial_delay: 500,
+            max_delay: 30_000,
+            multiplier: 2.0,
+            max_attempts: None,
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    /// A policy which never reconnects.
+    pub fn never() -> Self {
+        Self {
+            max_attempts: Some(0),
+            ..Self::default()
+        }
+    }
+
+    /// Returns the delay before reconnection attempt number `attempt` (starting at zero), or
+    /// `None` if the client should give up.
+    pub fn delay(&self, attempt: u32) -> Option<u32> {
+        if matches!(self.max_attempts, Some(max_attempts) if attempt >= max_attempts) {
+            return None;
+        }
+
+        // The exponent is capped as the delay reaches `max_delay` long before that anyway
+        let delay = f64::from(self.initial_delay) * self.multiplier.powi(attempt.min(64) as i32);
+        Some(delay.min(f64::from(self.max_delay)) as u32)
+    }
+}
+
+struct Connection {
+    socket: WebSocket,
+    _on_open: Closure<dyn FnMut()>,
+    _on_message: Closure<dyn FnMut(MessageEvent)>,
+    _on_error: Closure<dyn FnMut()>,
+    _on_close: Closure<dyn FnMut()>,
+}
+
+impl Drop for Connection {
+    fn drop(&mut self) {
+        self.socket.set_onopen(None);
+        self.socket.set_onmessage(None);
+        self.socket.set_onerror(None);
+        self.socket.set_onclose(None);
+        let _ = self.socket.close();
+    }
+}
+
+/// Number of messages buffered while the connection isn't open.
+const OUTBOX_CAPACITY: usize = 1024;
+
+struct Inner<In, F> {
+    url: String,
+    connection: Option<Connection>,
+    status: WebSocketStatus,
+    outbox: VecDeque<Vec<u8>>,
+    on_message: Callback<Result<In, FormatError>>,
+    on_status: Callback<WebSocketStatus>,
+    reconnect: ReconnectPolicy,
+    attempts: u32,
+    reconnect_timeout: Option<Timeout>,
+    closed: bool,
+    _format: PhantomData<F>,
+}
+
+fn send_frame<F: Format>(socket: &WebSocket, data: &[u8]) -> bool {
+    let result = match std::str::from_utf8(data) {
+        Ok(text) if F::IS_TEXT => socket.send_with_str(text),
+        _ => socket.send_with_u8_array(data),
+    };
+    result.is_ok()
+}
+
+fn set_status<In, F>(inner: &Shared<Inner<In, F>>, status: WebSocketStatus) {
+    let on_status = {
+        let mut inner = inner.borrow_mut();
+        if inner.status == status {
+            return;
+        }
+        inner.status = status;
+        inner.on_status.clone()
+    };
+    // Emit without holding the borrow, the callback may send messages
+    on_status.emit(status);
+}
+
+fn connect<In, F>(inner: &Shared<Inner<In, F>>)
+where
+    In: DeserializeOwned + 'static,
+    F: Format + 'static,
+{
+    let url = inner.borrow().url.clone();
+    let socket = match WebSocket::new(&url) {
+        Ok(socket) => socket,
+        Err(_) => {
+            // Only invalid URLs are rejected, so there's no point in trying again
+            {
+                let mut inner = inner.borrow_mut();
+                inner.closed = true;
+                inner.outbox.clear();
+            }
+            set_status(inner, WebSocketStatus::Failed);
+            return;
+        }
+    };
+    socket.set_binary_type(BinaryType::Arraybuffer);
+
+    let weak = Rc::downgrade(inner);
+    let on_open = {
+        let weak = weak.clone();
+        Closure::wrap(Box::new(move || {
+            if let Some(inner) = weak.upgrade() {
+                handle_open(&inner);
+            }
+        }) as Box<dyn FnMut()>)
+    };
+    let on_message = {
+        let weak = weak.clone();
+        Closure::wrap(Box::new(move |event: MessageEvent| {
+            if let Some(inner) = weak.upgrade() {
+                handle_message(&inner, event);
+            }
+        }) as Box<dyn FnMut(MessageEvent)>)
+    };
+    let on_error = {
+        let weak = weak.clone();
+        Closure::wrap(Box::new(move || {
+            if let Some(inner) = weak.upgrade() {
+                set_status(&inner, WebSocketStatus::Error);
+            }
+        }) as Box<dyn FnMut()>)
+    };
+    let on_close = Closure::wrap(Box::new(move || {
+        if let Some(inner) = weak.upgrade() {
+            handle_close(&inner);
+        }
+    }) as Box<dyn FnMut()>);
+
+    socket.set_onopen(Some(on_open.as_ref().unchecked_ref()));
+    socket.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+    socket.set_onerror(Some(on_error.as_ref().unchecked_ref()));
+    socket.set_onclose(Some(on_close.as_ref().unchecked_ref()));
+
+    inner.borrow_mut().connection = Some(Connection {
+        socket,
+        _on_open: on_open,
+        _on_message: on_message,
+        _on_error: on_error,
+        _on_close: on_close,
+    });
+    set_status(inner, WebSocketStatus::Connecting);
+}
+
+fn handle_open<In, F: Format>(inner: &Shared<Inner<In, F>>) {
+    {
+        let mut inner = inner.borrow_mut();
+        inner.attempts = 0;
+
+        let Inner {
+            connection, outbox, ..
+        } = &mut *inner;
+        if let Some(connection) = connection {
+            while let Some(data) = outbox.pop_front() {
+                if !send_frame::<F>(&connection.socket, &data) {
+                    outbox.push_front(data);
+                    break;
+                }
+            }
+        }
+    }
+    set_status(inner, WebSocketStatus::Open);
+}
+
+fn handle_message<In, F>(inner: &Shared<Inner<In, F>>, event: MessageEvent)
+where
+    In: DeserializeOwned,
+    F: Format,
+{
+    let data = event.data();
+    let bytes = if let Some(text) = data.as_string() {
+        text.into_bytes()
+    } else if let Some(buffer) = data.dyn_ref::<js_sys::ArrayBuffer>() {
+        js_sys::Uint8Array::new(buffer).to_vec()
+    } else {
+        return;
+    };
+
+    let on_message = inner.borrow().on_message.clone();
+    on_message.emit(F::decode(&bytes));
+}
+
+fn handle_close<In, F>(inner: &Shared<Inner<In, F>>)
+where
+    In: DeserializeOwned + 'static,
+    F: Format + 'static,
+{
+    let status = {
+        let mut inner = inner.borrow_mut();
+        inner.connection = None;
+        if inner.closed {
+            return;
+        }
+        // Keep reporting the error that caused the connection to close
+        match inner.status {
+            WebSocketStatus::Error => WebSocketStatus::Error,
+            _ => WebSocketStatus::Closed,
+        }
+    };
+    set_status(inner, status);
+    schedule_reconnect(inner);
+}
+
+fn schedule_reconnect<In, F>(inner: &Shared<Inner<In, F>>)
+where
+    In: DeserializeOwned + 'static,
+    F: Format + 'static,
+{
+    let mut inner_ref = inner.borrow_mut();
+    if inner_ref.closed {
+        return;
+    }
+    let delay = match inner_ref.reconnect.delay(inner_ref.attempts) {
+        Some(delay) => delay,
+        None => return,
+    };
+    inner_ref.attempts += 1;
+
+    let weak = Rc::downgrade(inner);
+    inner_ref.reconnect_timeout = Some(Timeout::new(delay, move || {
+        if let Some(inner) = weak.upgrade() {
+            connect(&inner);
+        }
+    }));
+}
+
+/// A WebSocket connection which receives messages of type `In` and sends messages of type
+/// `Out`, encoded with the format `F`.
+///
+/// The connection is closed when the task is dropped.
+pub struct WebSocketTask<In, Out, F = Json> {
+    inner: Shared<Inner<In, F>>,
+    _out: PhantomData<Out>,
+}
+
+impl<In, Out, F> fmt::Debug for WebSocketTask<In, Out, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("WebSocketTask<_>")
+    }
+}
+
+impl<In, Out, F> WebSocketTask<In, Out, F>
+where
+    In: DeserializeOwned + 'static,
+    Out: Serialize,
+    F: Format + 'static,
+{
+    /// Connects to `url`, reconnecting with the default [`ReconnectPolicy`] when the
+    /// connection is lost.
+    ///
+    /// `on_message` is called with every received message, `on_status` whenever the status of
+    /// the connection changes.
+    pub fn connect(
+        url: &str,
+        on_message: Callback<Result<In, FormatError>>,
+        on_status: Callback<WebSocketStatus>,
+    ) -> Self {
+        Self::connect_with_reconnect(url, on_message, on_status, ReconnectPolicy::default())
+    }
+
+    /// Connects to `url`, reconnecting according to `reconnect` when the connection is lost.
+    pub fn connect_with_reconnect(
+        url: &str,
+        on_message: Callback<Result<In, FormatError>>,
+        on_status: Callback<WebSocketStatus>,
+        reconnect: ReconnectPolicy,
+    ) -> Self {
+        let inner = Rc::new(RefCell::new(Inner {
+            url: url.to_owned(),
+            connection: None,
+            status: WebSocketStatus::Closed,
+            outbox: VecDeque::new(),
+            on_message,
+            on_status,
+            reconnect,
+            attempts: 0,
+            reconnect_timeout: None,
+            closed: false,
+            _format: PhantomData,
+        }));
+        connect(&inner);
+
+        Self {
+            inner,
+            _out: PhantomData,
+        }
+    }
+
+    /// Sends a message.
+    ///
+    /// If the connection isn't open, the message is buffered and sent once it is. At most 1024
+    /// messages are buffered, the oldest ones are dropped first. Messages sent after the
+    /// connection was closed for good are dropped.
+    pub fn send(&self, message: &Out) -> Result<(), FormatError> {
+        let data = F::encode(message)?;
+
+        let mut inner = self.inner.borrow_mut();
+        let sent = match (&inner.connection, inner.status) {
+            (Some(connection), WebSocketStatus::Open) => send_frame::<F>(&connection.socket, &data),
+            _ => false,
+        };
+        if !sent && !inner.closed {
+            if inner.outbox.len() == OUTBOX_CAPACITY {
+                inner.outbox.pop_front();
+            }
+            inner.outbox.push_back(data);
+        }
+        Ok(())
+    }
+}
+
+impl<In, Out, F> WebSocketTask<In, Out, F> {
+    /// Returns the current status of the connection.
+    pub fn status(&self) -> WebSocketStatus {
+        self.inner.borrow().status
+    }
+
+    /// Closes the connection for good. Buffered messages are discarded.
+    pub fn close(&self) {
+        self.shutdown();
+        set_status(&self.inner, WebSocketStatus::Closed);
+    }
+
+    fn shutdown(&self) {
+        let mut inner = self.inner.borrow_mut();
+        inner.closed = true;
+        inner.reconnect_timeout = None;
+        inner.connection = None;
+        inner.outbox.clear();
+    }
+}
+
+impl<In, Out, F> Drop for WebSocketTask<In, Out, F> {
+    fn drop(&mut self) {
+        // Nobody is left to observe the status, so don't emit it
+        self.shutdown();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reconnect_delay_grows_exponentially() {
+        let policy = ReconnectPolicy {
+            initial_delay: 100,
+            max_delay: 1000,
+            multiplier: 2.0,
+            max_attempts: None,
+        };
+
+        assert_eq!(policy.delay(0), Some(100));
+        assert_eq!(policy.delay(1), Some(200));
+        assert_eq!(policy.delay(3), Some(800));
+        assert_eq!(policy.delay(4), Some(1000));
+        assert_eq!(policy.delay(u32::MAX), Some(1000));
+    }
+
+    #[test]
+    fn reconnect_gives_up_after_max_attempts() {
+        let policy = ReconnectPolicy {
+            max_attempts: Some(2),
+            ..ReconnectPolicy::default()
+        };
+
+        assert!(policy.delay(1).is_some());
+        assert_eq!(policy.delay(2), None);
+        assert_eq!(ReconnectPolicy::never().delay(0), None);
+    }
+}
@@ -0,0 +1,116 @@
+mod common;
+
+use common::sleep;
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen_test::*;
+use yew::Callback;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+fn recorder() -> (Callback<u32>, Rc<RefCell<Vec<u32>>>) {
+    let calls = Rc::new(RefCell::new(Vec::new()));
+    let callback = {
+        let calls = Rc::clone(&calls);
+        Callback::from(move |value| calls.borrow_mut().push(value))
+    };
+    (callback, calls)
+}
+
+#[wasm_bindgen_test]
+fn filter_reform_and_reform() {
+    let (callback, calls) = recorder();
+
+    let even = callback.filter_reform(|value: u32| if value % 2 == 0 { Some(value) } else { None });
+    for value in 0..5 {
+        even.emit(value);
+    }
+    assert_eq!(*calls.borrow(), vec![0, 2, 4]);
+
+    let len = callback.reform(|value: &str| value.len() as u32);
+    len.emit("yew");
+    assert_eq!(*calls.borrow(), vec![0, 2, 4, 3]);
+}
+
+#[wasm_bindgen_test]
+fn map_converts_the_output() {
+    let (callback, calls) = recorder();
+
+    let emitted = callback.map(|()| true);
+    assert!(emitted.emit(1));
+    assert_eq!(*calls.borrow(), vec![1]);
+
+    let double = Callback::from(|value: u32| value * 2);
+    let text = double.map(|value| value.to_string());
+    assert_eq!(text.emit(21), "42");
+    assert_eq!(
+        text.reform(|value: &str| value.len() as u32).emit("yew"),
+        "6"
+    );
+}
+
+#[wasm_bindgen_test]
+async fn debounce_emits_latest_value() {
+    let (callback, calls) = recorder();
+    let debounced = callback.debounce(50);
+
+    debounced.emit(1);
+    debounced.emit(2);
+    sleep(20).await;
+    debounced.emit(3);
+    assert!(calls.borrow().is_empty());
+
+    sleep(100).await;
+    assert_eq!(*calls.borrow(), vec![3]);
+
+    // Dropping the callback cancels the pending call
+    debounced.emit(4);
+    drop(debounced);
+    sleep(100).await;
+    assert_eq!(*calls.borrow(), vec![3]);
+}
+
+#[wasm_bindgen_test]
+async fn throttle_emits_first_and_last_value() {
+    let (callback, calls) = recorder();
+    let throttled = callback.throttle(50);
+
+    throttled.emit(1);
+    throttled.emit(2);
+    throttled.emit(3);
+    assert_eq!(*calls.borrow(), vec![1]);
+
+    sleep(70).await;
+    assert_eq!(*calls.borrow(), vec![1, 3]);
+
+    sleep(70).await;
+    throttled.emit(4);
+    assert_eq!(*calls.borrow(), vec![1, 3, 4]);
+}
+
+#[wasm_bindgen_test]
+fn callbacks_return_values() {
+    let validate = Callback::from(|value: &str| {
+        if value.is_empty() {
+            Err("required")
+        } else {
+            Ok(())
+        }
+    });
+    assert_eq!(validate.emit(""), Err("required"));
+    assert_eq!(validate.emit("yew"), Ok(()));
+
+    let format = Callback::from(|value: u32| format!("{} items", value));
+    let shout = format.map(|text| text.to_uppercase());
+    assert_eq!(shout.emit(3), "3 ITEMS");
+    assert_eq!(
+        format.reform(|text: &str| text.len() as u32).emit("yew"),
+        "3 items"
+    );
+
+    let once = Callback::once(|value: u32| value * 2);
+    assert_eq!(once.emit(21), 42);
+
+    let noop: Callback<u32, Option<String>> = Callback::noop();
+    assert_eq!(noop.emit(1), None);
+}
@@ -13,3 +13,18 @@ pub fn obtain_result_by_id(id: &str) -> String {
         .expect("No result found. Most likely, the application crashed and burned")
         .inner_html()
 }
+
+/// Resolves after `millis` milliseconds, letting spawned futures and timers run in the meantime.
+pub async fn sleep(millis: i32) {
+    let promise = js_sys::Promise::new(&mut |resolve, _| {
+        yew::utils::window()
+            .set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, millis)
+            .unwrap();
+    });
+    wasm_bindgen_futures::JsFuture::from(promise).await.unwrap();
+}
+
+/// Yields to the browser's event loop so that spawned futures and timers get a chance to run.
+pub async fn next_tick() {
+    sleep(0).await;
+}
@@ -0,0 +1,101 @@
+use gloo::events::EventListener;
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_test::*;
+use web_sys::{CustomEvent, HtmlElement};
+use yew::custom_element::{register_custom_element, CustomElementHost, CustomElementProperties};
+use yew::functional::{FunctionComponent, FunctionProvider};
+use yew::{html, Callback, Html, Properties};
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Clone, PartialEq, Properties)]
+struct CounterProps {
+    count: i32,
+    label: Option<String>,
+    on_increment: Callback<i32>,
+}
+
+impl CustomElementProperties for CounterProps {
+    const OBSERVED_ATTRIBUTES: &'static [&'static str] = &["count"];
+    const OBSERVED_PROPERTIES: &'static [&'static str] = &["label"];
+
+    fn from_host(host: &CustomElementHost) -> Self {
+        Self {
+            count: host.attribute("count").unwrap_or_default(),
+            label: host.property_as("label").and_then(Result::ok),
+            on_increment: host.event("increment"),
+        }
+    }
+}
+
+struct CounterFunction {}
+impl FunctionProvider for CounterFunction {
+    type TProps = CounterProps;
+
+    fn run(props: &Self::TProps) -> Html {
+        let count = props.count;
+        let onclick = props.on_increment.reform(move |_| count + 1);
+        html! {
+            <button {onclick}>
+                { props.label.clone().unwrap_or_default() }{ count }
+            </button>
+        }
+    }
+}
+
+#[wasm_bindgen_test]
+fn custom_element_lifecycle() {
+    register_custom_element::<FunctionComponent<CounterFunction>>("yew-test-counter").unwrap();
+    // Names can only be registered once
+    assert!(
+        register_custom_element::<FunctionComponent<CounterFunction>>("yew-test-counter").is_err()
+    );
+
+    let document = yew::utils::document();
+    let element: HtmlElement = document
+        .create_element("yew-test-counter")
+        .unwrap()
+        .unchecked_into();
+    element.set_attribute("count", "3").unwrap();
+
+    let details = Rc::new(RefCell::new(Vec::new()));
+    let _listener = {
+        let details = Rc::clone(&details);
+        EventListener::new(&element, "increment", move |event| {
+            let event = event.unchecked_ref::<CustomEvent>();
+            details.borrow_mut().push(event.detail().as_f64().unwrap());
+        })
+    };
+
+    document.body().unwrap().append_child(&element).unwrap();
+    let root = element.shadow_root().expect("component wasn't mounted");
+    let button: HtmlElement = root
+        .query_selector("button")
+        .unwrap()
+        .unwrap()
+        .unchecked_into();
+    assert_eq!(button.text_content().unwrap(), "3");
+
+    // Callbacks dispatch events on the element
+    button.click();
+    assert_eq!(*details.borrow(), vec![4.0]);
+
+    // Attribute and property changes update the props
+    element.set_attribute("count", "4").unwrap();
+    assert_eq!(button.text_content().unwrap(), "4");
+    js_sys::Reflect::set(&element, &"label".into(), &JsValue::from_str("Count: ")).unwrap();
+    assert_eq!(button.text_content().unwrap(), "Count: 4");
+
+    // Disconnecting the element destroys the component
+    element.remove();
+    assert!(root.query_selector("button").unwrap().is_none());
+
+    // Connecting it again mounts a new one
+    document.body().unwrap().append_child(&element).unwrap();
+    let button = root.query_selector("button").unwrap().unwrap();
+    assert_eq!(button.text_content().unwrap(), "Count: 4");
+
+    element.remove();
+}
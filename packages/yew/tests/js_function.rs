@@ -0,0 +1,112 @@
+use serde::{Deserialize, Serialize};
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+use yew::callback::{JsCallback, JsFunction};
+use yew::{html, App, Callback, Component, Context, Html};
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn into_js_function_is_released_on_drop() {
+    let calls = Rc::new(Cell::new(0.0));
+    let callback = {
+        let calls = Rc::clone(&calls);
+        Callback::from(move |value: JsValue| calls.set(calls.get() + value.as_f64().unwrap()))
+    };
+
+    let handle = callback.into_js_function();
+    let function = handle.as_function().clone();
+    function.call1(&JsValue::NULL, &JsValue::from(2)).unwrap();
+    assert_eq!(calls.get(), 2.0);
+
+    // Calling the function after the handle was dropped is a no-op
+    drop(handle);
+    function.call1(&JsValue::NULL, &JsValue::from(2)).unwrap();
+    assert_eq!(calls.get(), 2.0);
+}
+
+#[wasm_bindgen_test]
+fn js_callback_converts_with_serde() {
+    #[derive(Deserialize)]
+    struct Input {
+        a: i32,
+        b: i32,
+    }
+
+    #[derive(Serialize)]
+    struct Output {
+        sum: i32,
+    }
+
+    let callback = JsCallback::new(|input: Input| Output {
+        sum: input.a + input.b,
+    });
+
+    let input = js_sys::JSON::parse(r#"{ "a": 1, "b": 2 }"#).unwrap();
+    let output = callback
+        .as_function()
+        .call1(&JsValue::NULL, &input)
+        .unwrap();
+    let sum = js_sys::Reflect::get(&output, &JsValue::from_str("sum")).unwrap();
+    assert_eq!(sum.as_f64(), Some(3.0));
+
+    // Arguments that can't be decoded are ignored
+    let output = callback
+        .as_function()
+        .call1(&JsValue::NULL, &JsValue::from_str("nope"))
+        .unwrap();
+    assert!(output.is_undefined());
+}
+
+#[wasm_bindgen_test]
+fn scope_js_callback_is_released_with_its_handle() {
+    thread_local! {
+        static FUNCTION: RefCell<Option<js_sys::Function>> = RefCell::new(None);
+        static MESSAGES: Cell<u32> = Cell::new(0);
+    }
+
+    struct Comp {
+        _function: JsFunction,
+    }
+    impl Component for Comp {
+        type Message = ();
+        type Properties = ();
+
+        fn create(ctx: &Context<Self>) -> Self {
+            let function = ctx.link().js_callback(|_: JsValue| ());
+            FUNCTION.with(|f| *f.borrow_mut() = Some(function.as_function().clone()));
+            // Aborting the futures of the component doesn't affect its functions
+            ctx.link().abort_futures();
+            Self {
+                _function: function,
+            }
+        }
+
+        fn update(&mut self, _ctx: &Context<Self>, _msg: Self::Message) -> bool {
+            MESSAGES.with(|m| m.set(m.get() + 1));
+            false
+        }
+
+        fn view(&self, _ctx: &Context<Self>) -> Html {
+            html! {}
+        }
+    }
+
+    let document = yew::utils::document();
+    let element = document.create_element("div").unwrap();
+    document.body().unwrap().append_child(&element).unwrap();
+    let app = App::<Comp>::builder().mount_point(element.clone()).mount();
+
+    let function = FUNCTION.with(|f| f.borrow_mut().take()).unwrap();
+    function.call1(&JsValue::NULL, &JsValue::NULL).unwrap();
+    assert_eq!(MESSAGES.with(Cell::get), 1);
+
+    // The component and the handle it owns are dropped when it's destroyed
+    app.destroy();
+    function.call1(&JsValue::NULL, &JsValue::NULL).unwrap();
+    assert_eq!(MESSAGES.with(Cell::get), 1);
+
+    element.remove();
+}
@@ -0,0 +1,50 @@
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::{HtmlElement, ShadowRootInit, ShadowRootMode};
+use yew::functional::{use_state, FunctionComponent, FunctionProvider};
+use yew::{html, Callback, Html};
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn events_are_handled_inside_shadow_root() {
+    struct CounterFunction {}
+    impl FunctionProvider for CounterFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            let counter = use_state(|| 0);
+            let onclick = {
+                let counter = counter.clone();
+                Callback::from(move |_| counter.set(*counter + 1))
+            };
+
+            html! {
+                <button id="counter" {onclick}>{ *counter }</button>
+            }
+        }
+    }
+
+    let document = yew::utils::document();
+    let host = document.create_element("div").unwrap();
+    document.body().unwrap().append_child(&host).unwrap();
+    let root = host
+        .attach_shadow(&ShadowRootInit::new(ShadowRootMode::Open))
+        .unwrap();
+
+    type UseComponent = FunctionComponent<CounterFunction>;
+    yew::start_app_in_shadow_root::<UseComponent>(root.clone());
+
+    // Elements inside the shadow root can't be found from the document
+    assert!(document.get_element_by_id("counter").is_none());
+    let button: HtmlElement = root
+        .get_element_by_id("counter")
+        .expect("app wasn't rendered into the shadow root")
+        .unchecked_into();
+    assert_eq!(button.inner_html(), "0");
+
+    button.click();
+    assert_eq!(button.inner_html(), "1");
+
+    host.remove();
+}
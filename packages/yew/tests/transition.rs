@@ -0,0 +1,194 @@
+mod common;
+
+use common::sleep;
+use wasm_bindgen_test::*;
+use yew::functional::{use_state, use_timeout, FunctionComponent, FunctionProvider};
+use yew::transition::{Transition, TransitionGroup};
+use yew::{html, Html};
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn transition_delays_removal_until_leave_ends() {
+    struct TransitionFunction {}
+    impl FunctionProvider for TransitionFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            let show = use_state(|| true);
+            {
+                let show = show.clone();
+                use_timeout(move || show.set(false), 10);
+            }
+
+            html! {
+                <Transition name="fade" show={*show} timeout={100}>
+                    <p id="item">{ "Hello" }</p>
+                </Transition>
+            }
+        }
+    }
+    type UseComponent = FunctionComponent<TransitionFunction>;
+    yew::start_app_in_element::<UseComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+
+    let item = || yew::utils::document().get_element_by_id("item");
+    assert_eq!(item().unwrap().class_name(), "");
+
+    sleep(50).await;
+    let class_list = item()
+        .expect("leaving element was removed too early")
+        .class_list();
+    assert!(class_list.contains("fade-leave-active"));
+    assert!(class_list.contains("fade-leave-to"));
+    assert!(!class_list.contains("fade-leave-from"));
+
+    sleep(200).await;
+    assert!(item().is_none());
+}
+
+#[wasm_bindgen_test]
+async fn transition_keeps_its_classes_when_the_class_changes() {
+    struct TransitionFunction {}
+    impl FunctionProvider for TransitionFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            let show = use_state(|| true);
+            let class = use_state(|| "before");
+            {
+                let show = show.clone();
+                use_timeout(move || show.set(false), 10);
+            }
+            {
+                let class = class.clone();
+                use_timeout(move || class.set("after"), 30);
+            }
+
+            html! {
+                <Transition name="fade" show={*show} timeout={200}>
+                    <p id="item" class={*class}>{ "Hello" }</p>
+                </Transition>
+            }
+        }
+    }
+    type UseComponent = FunctionComponent<TransitionFunction>;
+    yew::start_app_in_element::<UseComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+
+    sleep(60).await;
+    let class_list = yew::utils::document()
+        .get_element_by_id("item")
+        .unwrap()
+        .class_list();
+    assert!(class_list.contains("after"));
+    assert!(class_list.contains("fade-leave-active"));
+    assert!(class_list.contains("fade-leave-to"));
+
+    sleep(250).await;
+    assert!(yew::utils::document().get_element_by_id("item").is_none());
+}
+
+/// Renders a [`TransitionGroup`] of `<div>`s with the given ids, switching to the next list of
+/// ids after its delay. Consecutive delays have to differ to restart the timeout.
+fn start_group(steps: &'static [(u32, &'static [&'static str])]) {
+    #[derive(Clone, PartialEq, yew::Properties)]
+    struct GroupProps {
+        steps: &'static [(u32, &'static [&'static str])],
+    }
+
+    struct GroupFunction {}
+    impl FunctionProvider for GroupFunction {
+        type TProps = GroupProps;
+
+        fn run(props: &Self::TProps) -> Html {
+            let step = use_state(|| 0);
+            let delay = props.steps.get(*step + 1).map(|(delay, _)| *delay);
+            {
+                let step = step.clone();
+                use_timeout(move || step.set(*step + 1), delay);
+            }
+
+            let ids = props.steps[*step].1;
+            html! {
+                <div id="list">
+                    <TransitionGroup name="list" timeout={100}>
+                        { for ids.iter().map(|id| html! { <div key={*id} id={*id}>{ id }</div> }) }
+                    </TransitionGroup>
+                </div>
+            }
+        }
+    }
+    type UseComponent = FunctionComponent<GroupFunction>;
+    yew::start_app_with_props_in_element::<UseComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+        GroupProps { steps },
+    );
+}
+
+fn rendered_ids() -> Vec<String> {
+    let list = yew::utils::document().get_element_by_id("list").unwrap();
+    let mut ids = Vec::new();
+    let mut child = list.first_element_child();
+    while let Some(element) = child {
+        ids.push(element.id());
+        child = element.next_element_sibling();
+    }
+    ids
+}
+
+fn has_class(id: &str, class: &str) -> bool {
+    yew::utils::document()
+        .get_element_by_id(id)
+        .unwrap()
+        .class_list()
+        .contains(class)
+}
+
+#[wasm_bindgen_test]
+async fn transition_group_keeps_leaving_children_in_place() {
+    start_group(&[(0, &["a", "b", "c"]), (10, &["a", "c"])]);
+    assert_eq!(rendered_ids(), vec!["a", "b", "c"]);
+
+    sleep(50).await;
+    assert_eq!(rendered_ids(), vec!["a", "b", "c"]);
+    assert!(has_class("b", "list-leave-active"));
+
+    sleep(150).await;
+    assert_eq!(rendered_ids(), vec!["a", "c"]);
+}
+
+#[wasm_bindgen_test]
+async fn transition_group_moves_reordered_children() {
+    start_group(&[(0, &["a", "b", "c"]), (10, &["c", "a", "b"])]);
+
+    sleep(50).await;
+    assert_eq!(rendered_ids(), vec!["c", "a", "b"]);
+    for id in &["a", "b", "c"] {
+        assert!(has_class(id, "list-move"), "{} should be moving", id);
+    }
+
+    sleep(150).await;
+    for id in &["a", "b", "c"] {
+        assert!(!has_class(id, "list-move"), "{} should have stopped", id);
+    }
+}
+
+#[wasm_bindgen_test]
+async fn transition_group_readds_leaving_child() {
+    start_group(&[(0, &["a", "b"]), (10, &["a"]), (20, &["a", "b"])]);
+
+    sleep(20).await;
+    assert!(has_class("b", "list-leave-active"));
+
+    sleep(30).await;
+    assert_eq!(rendered_ids(), vec!["a", "b"]);
+    assert!(has_class("b", "list-enter-active"));
+    assert!(!has_class("b", "list-leave-active"));
+
+    sleep(150).await;
+    assert_eq!(rendered_ids(), vec!["a", "b"]);
+    assert!(!has_class("b", "list-enter-active"));
+}
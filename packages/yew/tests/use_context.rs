@@ -4,7 +4,8 @@ use common::obtain_result_by_id;
 use std::rc::Rc;
 use wasm_bindgen_test::*;
 use yew::functional::{
-    use_context, use_effect, use_ref, use_state, FunctionComponent, FunctionProvider,
+    use_context, use_context_selector, use_effect, use_ref, use_state, FunctionComponent,
+    FunctionProvider,
 };
 use yew::{html, Children, ContextProvider, Html, Properties};
 
@@ -294,3 +295,89 @@ fn use_context_update_works() {
         "current: hello world!, total: 4"
     );
 }
+
+#[wasm_bindgen_test]
+fn use_context_selector_only_rerenders_on_selected_change() {
+    #[derive(Clone, Debug, PartialEq)]
+    struct MyContext {
+        name: String,
+        revision: u32,
+    }
+
+    #[derive(Clone, Debug, PartialEq, Properties)]
+    struct NameOutletProps {
+        id: String,
+    }
+    struct NameOutletFunction;
+    impl FunctionProvider for NameOutletFunction {
+        type TProps = NameOutletProps;
+
+        fn run(props: &Self::TProps) -> Html {
+            let counter = use_ref(|| 0);
+            *counter.borrow_mut() += 1;
+
+            let name = use_context_selector(|ctx: &Rc<MyContext>| ctx.name.clone())
+                .expect("context not passed down");
+
+            return html! {
+                <div id={props.id.clone()}>
+                    { format!("current: {}, total: {}", name, counter.borrow()) }
+                </div>
+            };
+        }
+    }
+    type NameOutlet = FunctionComponent<NameOutletFunction>;
+
+    struct TestFunction;
+    impl FunctionProvider for TestFunction {
+        type TProps = ();
+
+        fn run(_props: &Self::TProps) -> Html {
+            type MyContextProvider = ContextProvider<Rc<MyContext>>;
+
+            let ctx = use_state(|| MyContext {
+                name: "hello".into(),
+                revision: 0,
+            });
+            let rendered = use_ref(|| 0);
+            {
+                let ctx = ctx.clone();
+                use_effect(move || {
+                    let count = *rendered.borrow();
+                    match count {
+                        0 | 1 => {
+                            // only the unselected field changes
+                            ctx.set(MyContext {
+                                name: ctx.name.clone(),
+                                revision: ctx.revision + 1,
+                            });
+                            *rendered.borrow_mut() += 1;
+                        }
+                        2 => {
+                            ctx.set(MyContext {
+                                name: "world".into(),
+                                revision: ctx.revision + 1,
+                            });
+                            *rendered.borrow_mut() += 1;
+                        }
+                        _ => (),
+                    };
+                    || {}
+                });
+            }
+            return html! {
+                <MyContextProvider context={Rc::new((*ctx).clone())}>
+                    <NameOutlet id="test-0"/>
+                </MyContextProvider>
+            };
+        }
+    }
+    type TestComponent = FunctionComponent<TestFunction>;
+
+    yew::start_app_in_element::<TestComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+
+    // 1 initial render + 1 name update, the two revision updates are skipped
+    assert_eq!(obtain_result_by_id("test-0"), "current: world, total: 2");
+}
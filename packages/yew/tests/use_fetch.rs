@@ -0,0 +1,65 @@
+mod common;
+
+use common::{next_tick, obtain_result};
+use serde::Deserialize;
+use wasm_bindgen_test::*;
+use yew::fetch::{Body, FetchClient, Request, Response};
+use yew::functional::{use_fetch, FunctionComponent, FunctionProvider};
+use yew::{html, ContextProvider, Html};
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn use_fetch_uses_provided_client() {
+    #[derive(Deserialize)]
+    struct User {
+        name: String,
+    }
+
+    struct UseFetchFunction {}
+    impl FunctionProvider for UseFetchFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            let user =
+                use_fetch::<User, _, _, _>((), || Request::get("/api/users/1").body(()).unwrap());
+            let text = match user.ready() {
+                None => "pending".to_string(),
+                Some(Ok(response)) => response.body().name.clone(),
+                Some(Err(err)) => err.to_string(),
+            };
+
+            html! {
+                <div id="result">{ text }</div>
+            }
+        }
+    }
+    type UseFetchComponent = FunctionComponent<UseFetchFunction>;
+
+    struct AppFunction {}
+    impl FunctionProvider for AppFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            let client = FetchClient::new(|request: Request<Body>| {
+                assert_eq!(request.uri().path(), "/api/users/1");
+                Ok(Response::new(br#"{"name":"Minka"}"#.to_vec()))
+            });
+
+            html! {
+                <ContextProvider<FetchClient> context={client}>
+                    <UseFetchComponent />
+                </ContextProvider<FetchClient>>
+            }
+        }
+    }
+    type AppComponent = FunctionComponent<AppFunction>;
+
+    yew::start_app_in_element::<AppComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+    assert_eq!(obtain_result().as_str(), "pending");
+
+    next_tick().await;
+    assert_eq!(obtain_result().as_str(), "Minka");
+}
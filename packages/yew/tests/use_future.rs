@@ -0,0 +1,211 @@
+mod common;
+
+use common::{next_tick, obtain_result, sleep};
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use wasm_bindgen_test::*;
+use yew::functional::{use_future, use_state, FunctionComponent, FunctionProvider, UseFutureState};
+use yew::{html, App, AppHandle, Component, Context, Html, Properties};
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn use_future_resolves() {
+    struct UseFutureFunction {}
+    impl FunctionProvider for UseFutureFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            let value = use_future((), || async { 42 });
+            let text = match value {
+                UseFutureState::Pending => "pending".to_string(),
+                UseFutureState::Ready(value) => value.to_string(),
+            };
+
+            html! {
+                <div id="result">{ text }</div>
+            }
+        }
+    }
+    type UseComponent = FunctionComponent<UseFutureFunction>;
+    yew::start_app_in_element::<UseComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+    assert_eq!(obtain_result().as_str(), "pending");
+
+    next_tick().await;
+    assert_eq!(obtain_result().as_str(), "42");
+}
+
+#[wasm_bindgen_test]
+async fn use_future_restarts_on_deps_change() {
+    struct UseFutureFunction {}
+    impl FunctionProvider for UseFutureFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            let counter = use_state(|| 0);
+            let deps = *counter;
+            let value = use_future(deps, move || async move { deps * 10 });
+            if let UseFutureState::Ready(value) = &value {
+                if **value < 30 {
+                    counter.set(*counter + 1);
+                }
+            }
+
+            html! {
+                <div id="result">{ format!("{:?}", value.ready()) }</div>
+            }
+        }
+    }
+    type UseComponent = FunctionComponent<UseFutureFunction>;
+    yew::start_app_in_element::<UseComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+
+    for _ in 0..8 {
+        next_tick().await;
+    }
+    assert_eq!(obtain_result().as_str(), "Some(30)");
+}
+
+#[derive(Properties, Clone, Default)]
+struct Recorder {
+    started: Rc<Cell<u32>>,
+    renders: Rc<Cell<u32>>,
+    messages: Rc<RefCell<Vec<u32>>>,
+}
+
+impl PartialEq for Recorder {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.messages, &other.messages)
+    }
+}
+
+impl Recorder {
+    /// A future which resolves to `value` some time after it's first polled.
+    fn delayed(&self, value: u32) -> impl std::future::Future<Output = u32> {
+        let started = Rc::clone(&self.started);
+        async move {
+            started.set(started.get() + 1);
+            sleep(20).await;
+            value
+        }
+    }
+}
+
+struct RecorderComponent;
+
+impl Component for RecorderComponent {
+    type Message = u32;
+    type Properties = Recorder;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        ctx.props().messages.borrow_mut().push(msg);
+        false
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        html! {}
+    }
+}
+
+fn mount_recorder(recorder: &Recorder) -> AppHandle<RecorderComponent> {
+    App::<RecorderComponent>::builder_with_props(recorder.clone())
+        .mount_point(yew::utils::document().get_element_by_id("output").unwrap())
+        .mount()
+}
+
+#[wasm_bindgen_test]
+async fn use_future_aborts_on_unmount() {
+    struct UseFutureFunction {}
+    impl FunctionProvider for UseFutureFunction {
+        type TProps = Recorder;
+
+        fn run(props: &Self::TProps) -> Html {
+            props.renders.set(props.renders.get() + 1);
+            let value = use_future((), {
+                let recorder = props.clone();
+                move || recorder.delayed(42)
+            });
+            if let UseFutureState::Ready(value) = value {
+                props.messages.borrow_mut().push(*value);
+            }
+
+            html! {}
+        }
+    }
+    type UseFutureComponent = FunctionComponent<UseFutureFunction>;
+
+    let recorder = Recorder::default();
+    let app = yew::start_app_with_props_in_element::<UseFutureComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+        recorder.clone(),
+    );
+
+    // Let the future start before the component goes away
+    next_tick().await;
+    assert_eq!(recorder.started.get(), 1);
+    let renders = recorder.renders.get();
+    app.destroy();
+
+    sleep(50).await;
+    assert_eq!(recorder.renders.get(), renders);
+    assert!(recorder.messages.borrow().is_empty());
+}
+
+#[wasm_bindgen_test]
+async fn send_future_aborts_on_destroy() {
+    let recorder = Recorder::default();
+    let app = mount_recorder(&recorder);
+    let handle = app.send_future(recorder.delayed(1));
+
+    next_tick().await;
+    assert_eq!(recorder.started.get(), 1);
+    app.destroy();
+
+    sleep(50).await;
+    assert!(handle.is_aborted());
+    assert!(recorder.messages.borrow().is_empty());
+}
+
+#[wasm_bindgen_test]
+async fn send_future_can_be_aborted() {
+    let recorder = Recorder::default();
+    let app = mount_recorder(&recorder);
+
+    let aborted = app.send_future(recorder.delayed(1));
+    let kept = app.send_future(recorder.delayed(2));
+    let batch = app.send_future_batch({
+        let future = recorder.delayed(3);
+        async move { vec![future.await, 4] }
+    });
+    next_tick().await;
+    assert_eq!(recorder.started.get(), 3);
+    aborted.abort();
+
+    sleep(50).await;
+    assert!(aborted.is_aborted());
+    assert!(kept.is_finished());
+    assert!(batch.is_finished());
+    assert_eq!(*recorder.messages.borrow(), vec![2, 3, 4]);
+
+    let first = app.send_future(recorder.delayed(5));
+    let second = app.send_future_batch({
+        let future = recorder.delayed(6);
+        async move { vec![future.await] }
+    });
+    next_tick().await;
+    assert_eq!(recorder.started.get(), 5);
+    app.abort_futures();
+
+    sleep(50).await;
+    assert!(first.is_aborted());
+    assert!(second.is_aborted());
+    assert_eq!(*recorder.messages.borrow(), vec![2, 3, 4]);
+    app.destroy();
+}
@@ -0,0 +1,37 @@
+mod common;
+
+use common::{obtain_result, sleep};
+use wasm_bindgen_test::*;
+use yew::functional::{use_interval, use_state, FunctionComponent, FunctionProvider};
+use yew::{html, Html};
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn use_interval_ticks_until_paused() {
+    struct UseIntervalFunction {}
+    impl FunctionProvider for UseIntervalFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            let counter = use_state(|| 0);
+            let millis = if *counter < 3 { Some(10) } else { None };
+            {
+                let counter = counter.clone();
+                use_interval(move || counter.set(*counter + 1), millis);
+            }
+
+            html! {
+                <div id="result">{ *counter }</div>
+            }
+        }
+    }
+    type UseComponent = FunctionComponent<UseIntervalFunction>;
+    yew::start_app_in_element::<UseComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+    assert_eq!(obtain_result().as_str(), "0");
+
+    sleep(200).await;
+    assert_eq!(obtain_result().as_str(), "3");
+}
@@ -0,0 +1,135 @@
+mod common;
+
+use common::obtain_result;
+use wasm_bindgen_test::*;
+use yew::functional::{
+    use_effect_with_deps, use_persisted_state, use_persisted_state_with, use_state,
+    FunctionComponent, FunctionProvider,
+};
+use yew::storage::Storage;
+use yew::{html, Html};
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn use_persisted_state_loads_and_writes() {
+    struct UsePersistedStateFunction {}
+    impl FunctionProvider for UsePersistedStateFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            let counter = use_persisted_state("use_persisted_state_counter", || 0);
+            {
+                let counter = counter.clone();
+                use_effect_with_deps(
+                    move |_| {
+                        counter.set(*counter + 1).unwrap();
+                        || {}
+                    },
+                    (),
+                );
+            }
+
+            html! {
+                <div id="result">{ *counter }</div>
+            }
+        }
+    }
+
+    let storage = Storage::local();
+    storage.set("use_persisted_state_counter", &41).unwrap();
+
+    type UseComponent = FunctionComponent<UsePersistedStateFunction>;
+    yew::start_app_in_element::<UseComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+
+    assert_eq!(obtain_result().as_str(), "42");
+    assert_eq!(
+        storage.get::<i32>("use_persisted_state_counter").unwrap(),
+        Some(42)
+    );
+    storage.remove("use_persisted_state_counter").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn use_persisted_state_reloads_when_the_key_changes() {
+    struct UsePersistedStateFunction {}
+    impl FunctionProvider for UsePersistedStateFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            let key = use_state(|| "use_persisted_state_first");
+            let value = use_persisted_state(*key, || 0);
+            {
+                let key = key.clone();
+                use_effect_with_deps(
+                    move |_| {
+                        key.set("use_persisted_state_second");
+                        || {}
+                    },
+                    (),
+                );
+            }
+
+            html! {
+                <div id="result">{ *value }</div>
+            }
+        }
+    }
+
+    let storage = Storage::local();
+    storage.set("use_persisted_state_first", &1).unwrap();
+    storage.set("use_persisted_state_second", &2).unwrap();
+
+    type UseComponent = FunctionComponent<UsePersistedStateFunction>;
+    yew::start_app_in_element::<UseComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+
+    assert_eq!(obtain_result().as_str(), "2");
+    storage.remove("use_persisted_state_first").unwrap();
+    storage.remove("use_persisted_state_second").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn use_persisted_state_reloads_when_the_storage_changes() {
+    struct UsePersistedStateFunction {}
+    impl FunctionProvider for UsePersistedStateFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            let namespace = use_state(|| "use_persisted_state_first");
+            let storage = Storage::local().with_namespace(*namespace);
+            let value = use_persisted_state_with(storage, "value", || 0);
+            {
+                let namespace = namespace.clone();
+                use_effect_with_deps(
+                    move |_| {
+                        namespace.set("use_persisted_state_second");
+                        || {}
+                    },
+                    (),
+                );
+            }
+
+            html! {
+                <div id="result">{ *value }</div>
+            }
+        }
+    }
+
+    let first = Storage::local().with_namespace("use_persisted_state_first");
+    let second = Storage::local().with_namespace("use_persisted_state_second");
+    first.set("value", &1).unwrap();
+    second.set("value", &2).unwrap();
+
+    type UseComponent = FunctionComponent<UsePersistedStateFunction>;
+    yew::start_app_in_element::<UseComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+
+    assert_eq!(obtain_result().as_str(), "2");
+    first.remove("value").unwrap();
+    second.remove("value").unwrap();
+}
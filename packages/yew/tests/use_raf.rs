@@ -0,0 +1,37 @@
+mod common;
+
+use common::{obtain_result, sleep};
+use wasm_bindgen_test::*;
+use yew::functional::{use_raf, use_state, FunctionComponent, FunctionProvider};
+use yew::{html, Html};
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn use_raf_runs_until_stopped() {
+    struct UseRafFunction {}
+    impl FunctionProvider for UseRafFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            let frames = use_state(|| 0);
+            let running = *frames < 3;
+            {
+                let frames = frames.clone();
+                use_raf(move |_| frames.set(*frames + 1), running);
+            }
+
+            html! {
+                <div id="result">{ *frames }</div>
+            }
+        }
+    }
+    type UseComponent = FunctionComponent<UseRafFunction>;
+    yew::start_app_in_element::<UseComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+    assert_eq!(obtain_result().as_str(), "0");
+
+    sleep(500).await;
+    assert_eq!(obtain_result().as_str(), "3");
+}
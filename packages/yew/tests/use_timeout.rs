@@ -0,0 +1,36 @@
+mod common;
+
+use common::{obtain_result, sleep};
+use wasm_bindgen_test::*;
+use yew::functional::{use_state, use_timeout, FunctionComponent, FunctionProvider};
+use yew::{html, Html};
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn use_timeout_fires_once() {
+    struct UseTimeoutFunction {}
+    impl FunctionProvider for UseTimeoutFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            let counter = use_state(|| 0);
+            {
+                let counter = counter.clone();
+                use_timeout(move || counter.set(*counter + 1), 10);
+            }
+
+            html! {
+                <div id="result">{ *counter }</div>
+            }
+        }
+    }
+    type UseComponent = FunctionComponent<UseTimeoutFunction>;
+    yew::start_app_in_element::<UseComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+    assert_eq!(obtain_result().as_str(), "0");
+
+    sleep(100).await;
+    assert_eq!(obtain_result().as_str(), "1");
+}
@@ -0,0 +1,128 @@
+mod common;
+
+use common::{obtain_result, sleep};
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen_test::*;
+use yew::functional::{use_websocket, FunctionComponent, FunctionProvider};
+use yew::websocket::{ReconnectPolicy, WebSocketStatus, WebSocketTask};
+use yew::{html, Callback, Html};
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+// Nothing listens on this port, so connections are refused right away
+const UNREACHABLE_URL: &str = "ws://127.0.0.1:65535";
+
+fn record_statuses() -> (Rc<RefCell<Vec<WebSocketStatus>>>, Callback<WebSocketStatus>) {
+    let statuses = Rc::new(RefCell::new(Vec::new()));
+    let on_status = {
+        let statuses = Rc::clone(&statuses);
+        Callback::from(move |status| statuses.borrow_mut().push(status))
+    };
+    (statuses, on_status)
+}
+
+fn fast_reconnect() -> ReconnectPolicy {
+    ReconnectPolicy {
+        initial_delay: 10,
+        max_delay: 10,
+        ..ReconnectPolicy::default()
+    }
+}
+
+#[wasm_bindgen_test]
+async fn invalid_url_fails_without_reconnecting() {
+    let (statuses, on_status) = record_statuses();
+    let task = WebSocketTask::<String, String>::connect_with_reconnect(
+        "not a websocket url",
+        Callback::noop(),
+        on_status,
+        fast_reconnect(),
+    );
+    assert_eq!(task.status(), WebSocketStatus::Failed);
+
+    sleep(100).await;
+    assert_eq!(task.status(), WebSocketStatus::Failed);
+    assert_eq!(*statuses.borrow(), vec![WebSocketStatus::Failed]);
+}
+
+#[wasm_bindgen_test]
+async fn refused_connection_reports_an_error() {
+    let (statuses, on_status) = record_statuses();
+    let task = WebSocketTask::<String, String>::connect_with_reconnect(
+        UNREACHABLE_URL,
+        Callback::noop(),
+        on_status,
+        ReconnectPolicy::never(),
+    );
+    assert_eq!(task.status(), WebSocketStatus::Connecting);
+
+    sleep(500).await;
+    assert_eq!(task.status(), WebSocketStatus::Error);
+    assert_eq!(
+        *statuses.borrow(),
+        vec![WebSocketStatus::Connecting, WebSocketStatus::Error]
+    );
+}
+
+#[wasm_bindgen_test]
+async fn refused_connection_is_retried() {
+    let (statuses, on_status) = record_statuses();
+    let _task = WebSocketTask::<String, String>::connect_with_reconnect(
+        UNREACHABLE_URL,
+        Callback::noop(),
+        on_status,
+        fast_reconnect(),
+    );
+
+    sleep(500).await;
+    let connecting = statuses
+        .borrow()
+        .iter()
+        .filter(|status| **status == WebSocketStatus::Connecting)
+        .count();
+    assert!(connecting > 1, "statuses: {:?}", statuses.borrow());
+}
+
+#[wasm_bindgen_test]
+async fn close_stops_the_connection() {
+    let (statuses, on_status) = record_statuses();
+    let task = WebSocketTask::<String, String>::connect_with_reconnect(
+        UNREACHABLE_URL,
+        Callback::noop(),
+        on_status,
+        fast_reconnect(),
+    );
+    task.close();
+    assert_eq!(task.status(), WebSocketStatus::Closed);
+
+    sleep(100).await;
+    assert_eq!(task.status(), WebSocketStatus::Closed);
+    assert_eq!(
+        *statuses.borrow(),
+        vec![WebSocketStatus::Connecting, WebSocketStatus::Closed]
+    );
+}
+
+#[wasm_bindgen_test]
+async fn use_websocket_renders_the_status() {
+    struct UseWebSocketFunction {}
+    impl FunctionProvider for UseWebSocketFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            let socket = use_websocket::<String, String>("not a websocket url");
+            html! {
+                <div id="result">{ format!("{:?}", socket.status()) }</div>
+            }
+        }
+    }
+
+    type UseComponent = FunctionComponent<UseWebSocketFunction>;
+    yew::start_app_in_element::<UseComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+
+    sleep(10).await;
+    assert_eq!(obtain_result().as_str(), "Failed");
+}
@@ -57,6 +57,7 @@ module.exports = {
                         "concepts/html/components",
                         "concepts/html/elements",
                         "concepts/html/classes",
+                        "concepts/html/styles",
                         "concepts/html/lists",
                         "concepts/html/literals-and-expressions"
                     ]